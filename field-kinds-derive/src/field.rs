@@ -1,6 +1,6 @@
 use convert_case::Case;
 use proc_macro2::Ident;
-use syn::Type;
+use syn::{Member, Type};
 
 /// Rename rule matching serde's `rename_all` variants.
 #[derive(Clone, Copy)]
@@ -14,33 +14,317 @@ pub enum RenameRule<'a> {
     Uppercase,
 }
 
+impl RenameRule<'_> {
+    /// Applies the rule to a field or variant name.
+    pub fn apply(self, name: &str) -> String {
+        use convert_case::Casing;
+
+        match self {
+            Self::Case(case) => name.to_case(case),
+            Self::Lowercase => name.to_lowercase(),
+            Self::Uppercase => name.to_uppercase(),
+        }
+    }
+}
+
+/// A serde setting that may differ per direction, e.g.
+/// `rename(serialize = "a", deserialize = "b")`.
+#[derive(Clone, Copy)]
+pub struct Directional<T> {
+    pub serialize: Option<T>,
+    pub deserialize: Option<T>,
+}
+
+impl<T> Default for Directional<T> {
+    fn default() -> Self {
+        Self {
+            serialize: None,
+            deserialize: None,
+        }
+    }
+}
+
+impl<T> Directional<T> {
+    /// Per direction, falls back to `other` where this value is unset.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            serialize: self.serialize.or(other.serialize),
+            deserialize: self.deserialize.or(other.deserialize),
+        }
+    }
+}
+
+/// Contents of `#[field_tags(...)]`.
+#[derive(Default)]
+pub struct Tags {
+    /// Bare string tags: `"pii"`.
+    pub names: Vec<String>,
+    /// Key/value tags: `max_len = 64`.
+    pub values: Vec<(String, TagValue)>,
+}
+
+/// Value of a key/value tag.
+pub enum TagValue {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Container-level attributes recorded in `TypeMeta`.
+pub struct ParsedContainer {
+    /// `#[serde(rename = "...")]` on the container.
+    pub rename: Directional<String>,
+    /// `#[field_tags(...)]` on the container.
+    pub tags: Tags,
+    /// `#[field_kinds(exclude_serde_skipped)]` on the container.
+    pub exclude_serde_skipped: bool,
+}
+
+/// `rename_all` rules for both directions.
+pub type RenameAll = Directional<RenameRule<'static>>;
+
+/// Category set with `#[field_kinds(category = ...)]`, used instead of
+/// `<T as Categorized>::Category`.
+pub enum CategoryOverride {
+    /// Built-in marker named by string: `"numeric"` -> `Numeric`.
+    Builtin(Ident),
+    /// Custom `TypeCategory` marker: `category = my::Money`.
+    Path(syn::Path),
+}
+
+#[allow(clippy::struct_excessive_bools)]
 pub struct ParsedField {
-    pub ident: Ident,
+    /// Field name for named fields, position for tuple fields.
+    pub member: Member,
+    /// Declaration position, counting skipped fields.
+    pub index: usize,
     pub ty: Type,
-    pub rename: Option<String>,
-    pub tags: Vec<String>,
+    pub rename: Directional<String>,
+    /// Extra names accepted when deserializing, from `#[serde(alias)]`.
+    pub aliases: Vec<String>,
+    pub tags: Tags,
+    /// `#[field_kinds(category = ...)]`
+    pub category: Option<CategoryOverride>,
+    /// Container-level `#[field_kinds(unknown_fallback)]`: the category is
+    /// probed, falling back to `Unknown` if the type isn't `Categorized`.
+    pub unknown_fallback: bool,
     pub skip: bool,
+    /// `#[serde(flatten)]`: the field contributes the inner type's fields.
+    pub flatten: bool,
+    /// `#[serde(flatten)]` on a map or `Option`, whose keys aren't known:
+    /// kept as an ordinary entry with `flattened_from` set to itself.
+    pub flatten_opaque: bool,
+    /// `#[serde(skip)]` or `#[serde(skip_serializing)]`
+    pub skip_serializing: bool,
+    /// `#[serde(skip)]` or `#[serde(skip_deserializing)]`
+    pub skip_deserializing: bool,
+    /// The type names one of the container's generic parameters, so its
+    /// element categories can't be built in a nested `const`.
+    pub generic: bool,
 }
 
 impl ParsedField {
-    /// Name of the marker type: `user_name` -> `UserName`
+    /// Field name as written in Rust: `user_name`, or `0` for tuple fields
+    pub fn name(&self) -> String {
+        match &self.member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+
+    /// Name of the marker type: `user_name` -> `UserName`, `0` -> `Field0`
     pub fn marker_type_name(&self) -> Ident {
         use convert_case::Casing;
-        quote::format_ident!("{}", self.ident.to_string().to_case(Case::Pascal))
+        match &self.member {
+            Member::Named(ident) => quote::format_ident!(
+                "{}",
+                ident.to_string().to_case(Case::Pascal)
+            ),
+            Member::Unnamed(index) => {
+                quote::format_ident!("Field{}", index.index)
+            }
+        }
     }
 
-    /// Serialized name considering rename and `rename_all`
-    pub fn serialized_name(&self, rename_all: Option<RenameRule>) -> String {
-        use convert_case::Casing;
+    /// `T` if the field is written as `Option<T>` (matched by name, like
+    /// serde does).
+    pub fn option_inner(&self) -> Option<&Type> {
+        element_type(&self.ty, &["Option"])
+    }
+
+    /// `T` if the field is written as a list or set of `T` (`Vec<T>`,
+    /// `VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>`), matched by name.
+    pub fn list_inner(&self) -> Option<&Type> {
+        element_type(&self.ty, &["Vec", "VecDeque", "HashSet", "BTreeSet"])
+    }
+
+    /// Whether the category is probed rather than taken from `Categorized`
+    /// or an explicit override.
+    pub const fn probes_category(&self) -> bool {
+        self.unknown_fallback && self.category.is_none()
+    }
 
-        self.rename.clone().unwrap_or_else(|| {
+    /// Whether the field keeps its type's numeric, element and schema
+    /// metadata despite a category override: `identifier` and `binary` only
+    /// relabel the type (an `u64` id is still a number).
+    pub fn keeps_type_metadata(&self) -> bool {
+        matches!(
+            &self.category,
+            Some(CategoryOverride::Builtin(marker))
+                if marker == "Identifier" || marker == "Binary"
+        )
+    }
+
+    /// Name of the fallback category marker: `user_name` ->
+    /// `__UserNameCategory`. Field markers are Pascal case without leading
+    /// underscores, so a field named `user_name_category` can't collide.
+    pub fn category_marker_name(&self) -> Ident {
+        quote::format_ident!("__{}Category", self.marker_type_name())
+    }
+
+    /// Serialized name considering rename and `rename_all`.
+    /// Positional fields are never affected by `rename_all`.
+    pub fn serialized_name(&self, rename_all: RenameAll) -> String {
+        self.renamed(self.rename.serialize.as_ref(), rename_all.serialize)
+    }
+
+    /// Deserialized name considering rename and `rename_all`.
+    pub fn deserialize_name(&self, rename_all: RenameAll) -> String {
+        self.renamed(self.rename.deserialize.as_ref(), rename_all.deserialize)
+    }
+
+    fn renamed(
+        &self,
+        rename: Option<&String>,
+        rule: Option<RenameRule>,
+    ) -> String {
+        rename
+            .cloned()
+            .unwrap_or_else(|| match (&self.member, rule) {
+                (Member::Named(_), Some(rule)) => rule.apply(&self.name()),
+                _ => self.name(),
+            })
+    }
+}
+
+/// Shape of an enum variant.
+#[derive(Clone, Copy)]
+pub enum VariantShape {
+    Unit,
+    Tuple,
+    Struct,
+}
+
+pub struct ParsedVariant {
+    pub ident: Ident,
+    pub shape: VariantShape,
+    pub rename: Directional<String>,
+    /// Variant-level `rename_all`, applied to the variant's fields.
+    pub rename_all: RenameAll,
+    pub fields: Vec<ParsedField>,
+    pub skip: bool,
+}
+
+impl ParsedVariant {
+    /// Serialized name considering rename and the enum's `rename_all`
+    pub fn serialized_name(&self, rename_all: RenameAll) -> String {
+        self.renamed(self.rename.serialize.as_ref(), rename_all.serialize)
+    }
+
+    /// Deserialized name considering rename and the enum's `rename_all`
+    pub fn deserialize_name(&self, rename_all: RenameAll) -> String {
+        self.renamed(self.rename.deserialize.as_ref(), rename_all.deserialize)
+    }
+
+    fn renamed(
+        &self,
+        rename: Option<&String>,
+        rule: Option<RenameRule>,
+    ) -> String {
+        rename.cloned().unwrap_or_else(|| {
             let name = self.ident.to_string();
-            match rename_all {
+            match rule {
                 None => name,
-                Some(RenameRule::Case(case)) => name.to_case(case),
-                Some(RenameRule::Lowercase) => name.to_lowercase(),
-                Some(RenameRule::Uppercase) => name.to_uppercase(),
+                Some(rule) => rule.apply(&name),
             }
         })
     }
 }
+
+/// How a field type nests other types, for reading element categories.
+pub enum TypeShape<'a> {
+    /// `Option<T>`, `Vec<T>`, sets, arrays, slices and `Cow<[T]>`.
+    Element(&'a Type),
+    /// `HashMap<K, V>` and `BTreeMap<K, V>`: key and value.
+    Map(&'a Type, &'a Type),
+    /// Smart pointers and wrappers forwarding the wrapped type's category.
+    Wrapper(&'a Type),
+    Other,
+}
+
+impl<'a> TypeShape<'a> {
+    /// Matches the std containers and wrappers with a `Categorized` impl by
+    /// name, like `option_inner()`.
+    pub fn of(ty: &'a Type) -> Self {
+        let path = match ty {
+            Type::Array(array) => return Self::Element(&array.elem),
+            Type::Reference(reference) => {
+                return match &*reference.elem {
+                    Type::Slice(slice) => Self::Element(&slice.elem),
+                    _ => Self::Other,
+                };
+            }
+            Type::Group(group) => return Self::of(&group.elem),
+            Type::Paren(paren) => return Self::of(&paren.elem),
+            Type::Path(path) => path,
+            _ => return Self::Other,
+        };
+        let Some(segment) = path.path.segments.last() else {
+            return Self::Other;
+        };
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        else {
+            return Self::Other;
+        };
+        let mut types = args.args.iter().filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+        let Some(first) = types.next() else {
+            return Self::Other;
+        };
+        match segment.ident.to_string().as_str() {
+            "Option" | "Vec" | "HashSet" | "BTreeSet" => Self::Element(first),
+            "HashMap" | "BTreeMap" => types
+                .next()
+                .map_or(Self::Other, |value| Self::Map(first, value)),
+            "Cow" => match first {
+                Type::Slice(slice) => Self::Element(&slice.elem),
+                _ => Self::Wrapper(first),
+            },
+            "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock"
+            | "Wrapping" | "Saturating" | "Reverse" => Self::Wrapper(first),
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The first type argument of `ty` if its last path segment is one of
+/// `names`, e.g. `T` for `Option<T>`.
+fn element_type<'a>(ty: &'a Type, names: &[&str]) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !names.iter().any(|name| segment.ident == name) {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -1,41 +1,574 @@
+use std::collections::HashSet;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{GenericParam, Generics, Ident};
+use syn::{GenericParam, Generics, Ident, Type, Visibility};
 
-use crate::field::{ParsedField, RenameRule};
+use crate::field::{
+    CategoryOverride, ParsedContainer, ParsedField, ParsedVariant, RenameAll,
+    TagValue, TypeShape, VariantShape,
+};
 
 pub fn generate_all(
     struct_name: &Ident,
+    vis: &Visibility,
     generics: &Generics,
     fields: &[ParsedField],
-    rename_all: Option<RenameRule>,
+    container: &ParsedContainer,
+    rename_all: RenameAll,
     crate_path: &TokenStream,
 ) -> TokenStream {
     let mod_name = module_name(struct_name);
     let active_fields: Vec<_> = fields.iter().filter(|f| !f.skip).collect();
+    // Flattened fields have no marker: their type is a struct, not a category.
+    let marker_fields: Vec<_> = active_fields
+        .iter()
+        .copied()
+        .filter(|f| !f.flatten)
+        .collect();
 
-    let field_types =
-        generate_field_types(&active_fields, rename_all, generics, crate_path);
+    let (markers, field_info_impls) = generate_field_types(
+        &mod_name,
+        &marker_visibility(vis),
+        &marker_fields,
+        rename_all,
+        generics,
+        crate_path,
+    );
     let visit_impl = generate_visit_impl(
         struct_name,
         generics,
         &active_fields,
+        container,
         rename_all,
         crate_path,
     );
     quote! {
         #[doc(hidden)]
         pub mod #mod_name {
+            #[allow(unused_imports)]
             use super::*;
-            use #crate_path::{FieldInfo, Categorized, TypeCategory};
 
-            #field_types
+            #markers
         }
 
+        #field_info_impls
+
         #visit_impl
     }
 }
 
+/// Implements `FieldAccess` for a deriving struct: own fields are matched
+/// by name, other names are looked up in the flattened fields in order.
+pub fn generate_field_access(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &[ParsedField],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let generics = static_generics(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let active = fields.iter().filter(|f| !f.skip);
+    let (flattened, own): (Vec<_>, Vec<_>) = active.partition(|f| f.flatten);
+    let names: Vec<_> = own.iter().map(|f| f.name()).collect();
+    let members: Vec<_> = own.iter().map(|f| &f.member).collect();
+    let flattened: Vec<_> = flattened.iter().map(|f| &f.member).collect();
+
+    quote! {
+        impl #impl_generics #crate_path::FieldAccess for #struct_name #ty_generics #where_clause {
+            fn get(&self, name: &str) -> Option<&dyn ::core::any::Any> {
+                match name {
+                    #(#names => return Some(&self.#members),)*
+                    _ => {}
+                }
+                #(
+                    if let Some(value) = #crate_path::FieldAccess::get(&self.#flattened, name) {
+                        return Some(value);
+                    }
+                )*
+                None
+            }
+
+            fn get_mut(&mut self, name: &str) -> Option<&mut dyn ::core::any::Any> {
+                match name {
+                    #(#names => return Some(&mut self.#members),)*
+                    _ => {}
+                }
+                #(
+                    if let Some(value) = #crate_path::FieldAccess::get_mut(&mut self.#flattened, name) {
+                        return Some(value);
+                    }
+                )*
+                None
+            }
+        }
+    }
+}
+
+/// Implements `VisitValues` for a deriving struct, pairing each value with
+/// its entry in `FIELDS`. Flattened entries are read through the inner
+/// type's `FieldAccess` so they keep their outer metadata.
+pub fn generate_visit_values(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &[ParsedField],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let generics = static_generics(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut own_before = 0usize;
+    let mut flattened_before = Vec::new();
+    let visits = fields.iter().filter(|f| !f.skip).map(|field| {
+        let offset = quote! {
+            #own_before #(+ <#flattened_before as #crate_path::VisitFields>::FIELD_COUNT)*
+        };
+        let member = &field.member;
+        let ty = &field.ty;
+        if field.flatten {
+            flattened_before.push(ty.clone());
+            quote! {
+                let start = #offset;
+                let end = start + <#ty as #crate_path::VisitFields>::FIELD_COUNT;
+                for meta in &fields[start..end] {
+                    if let Some(value) = #crate_path::FieldAccess::get(&self.#member, meta.name) {
+                        visitor.visit_field(meta, value);
+                    }
+                }
+            }
+        } else {
+            own_before += 1;
+            quote! { visitor.visit_field(&fields[#offset], &self.#member); }
+        }
+    });
+    let visits: Vec<_> = visits.collect();
+
+    quote! {
+        impl #impl_generics #crate_path::VisitValues for #struct_name #ty_generics #where_clause {
+            fn visit_values(&self, visitor: &mut impl #crate_path::FieldValueVisitor) {
+                let fields = <Self as #crate_path::VisitFields>::FIELDS;
+                #(#visits)*
+            }
+        }
+    }
+}
+
+/// Implements `SetFieldFromStr` for a deriving struct. Each field matches
+/// its Rust and serialized name; a serialized name equal to another field's
+/// Rust name is left to that field. Other names go to the flattened fields.
+/// `Option<T>` fields parse `T`, with an empty value meaning `None`.
+pub fn generate_set_from_str(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &[ParsedField],
+    rename_all: RenameAll,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let active = fields.iter().filter(|f| !f.skip);
+    let (flattened, own): (Vec<_>, Vec<_>) = active.partition(|f| f.flatten);
+    let mut seen: HashSet<String> = own.iter().map(|f| f.name()).collect();
+    let arms = own.iter().map(|field| {
+        let name = field.name();
+        let serialized = field.serialized_name(rename_all);
+        let aliases = seen
+            .insert(serialized.clone())
+            .then_some(serialized)
+            .into_iter();
+        let member = &field.member;
+        let ty = &field.ty;
+        let parsed = field.option_inner().map_or_else(
+            || quote! { #crate_path::__private::ParseProbe::<#ty>::parse(#name, value)? },
+            |inner| {
+                quote! {
+                    if value.is_empty() {
+                        None
+                    } else {
+                        Some(#crate_path::__private::ParseProbe::<#inner>::parse(#name, value)?)
+                    }
+                }
+            },
+        );
+        quote! {
+            #name #(| #aliases)* => {
+                #[allow(unused_imports)]
+                use #crate_path::__private::ParseFallback as _;
+                self.#member = #parsed;
+                return Ok(());
+            }
+        }
+    });
+    let arms: Vec<_> = arms.collect();
+    let flattened = flattened.iter().map(|f| &f.member);
+
+    quote! {
+        impl #impl_generics #crate_path::SetFieldFromStr for #struct_name #ty_generics #where_clause {
+            fn set_field_from_str(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<(), #crate_path::FieldSetError> {
+                match name {
+                    #(#arms)*
+                    _ => {}
+                }
+                #(
+                    match #crate_path::SetFieldFromStr::set_field_from_str(&mut self.#flattened, name, value) {
+                        Err(#crate_path::FieldSetError::UnknownField { .. }) => {}
+                        result => return result,
+                    }
+                )*
+                Err(#crate_path::FieldSetError::UnknownField { name: name.to_owned() })
+            }
+        }
+    }
+}
+
+/// Adds a `'static` bound to every type parameter, as values are handed
+/// out as `dyn Any`.
+fn static_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    let type_params: Vec<_> =
+        generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: 'static));
+    }
+    generics
+}
+
+/// Implements `FromEnv` for a struct opting in with `#[field_kinds(env)]`.
+/// `set_env_var` parses list fields as comma-separated values and hands
+/// dotted paths to the named field; everything else, including flattened
+/// fields that aren't `FromEnv`, goes through `SetFieldFromStr`.
+pub fn generate_from_env(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &[ParsedField],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let active = fields.iter().filter(|f| !f.skip);
+    let (flattened, own): (Vec<_>, Vec<_>) = active.partition(|f| f.flatten);
+    let nested: Vec<_> = own.iter().map(|field| {
+        let name = field.name();
+        let member = &field.member;
+        quote! {
+            #name => return #crate_path::__private::EnvProbe(&mut self.#member).set_env_var(rest, value),
+        }
+    }).collect();
+    let lists = own.iter().filter_map(|field| {
+        let name = field.name();
+        let member = &field.member;
+        let inner = field.list_inner()?;
+        Some(quote! {
+            #name => {
+                self.#member = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| #crate_path::__private::ParseProbe::<#inner>::parse(#name, item))
+                    .collect::<Result<_, _>>()?;
+                return Ok(());
+            }
+        })
+    });
+    let own_fields = (!own.is_empty()).then(|| {
+        quote! {
+            if let Some((name, rest)) = path.split_once('.') {
+                match name {
+                    #(#nested)*
+                    _ => {}
+                }
+            } else {
+                match path {
+                    #(#lists)*
+                    _ => {}
+                }
+            }
+        }
+    });
+    let flattened = flattened.iter().map(|f| &f.member);
+
+    quote! {
+        impl #impl_generics #crate_path::FromEnv for #struct_name #ty_generics #where_clause {
+            fn set_env_var(
+                &mut self,
+                path: &str,
+                value: &str,
+            ) -> Result<(), #crate_path::FieldSetError> {
+                #[allow(unused_imports)]
+                use #crate_path::__private::{EnvFallback as _, ParseFallback as _};
+                #own_fields
+                #(
+                    match #crate_path::__private::EnvProbe(&mut self.#flattened).set_env_var(path, value) {
+                        Err(#crate_path::FieldSetError::UnknownField { .. }) => {}
+                        result => return result,
+                    }
+                )*
+                #crate_path::SetFieldFromStr::set_field_from_str(self, path, value)
+            }
+        }
+    }
+}
+
+/// Implements `FieldDiff` for a struct opting in with `#[field_kinds(diff)]`.
+/// Own fields are compared with `!=`; flattened fields delegate to their
+/// own `FieldDiff` and report the spliced metadata of the outer struct.
+pub fn generate_diff(
+    struct_name: &Ident,
+    generics: &Generics,
+    fields: &[ParsedField],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let mut generics = generics.clone();
+    let type_params: Vec<_> =
+        generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause.predicates.push(syn::parse_quote!(
+            #param: ::core::cmp::PartialEq + ::core::fmt::Debug
+        ));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut own_before = 0usize;
+    let mut flattened_before = Vec::new();
+    let compares = fields.iter().filter(|f| !f.skip).map(|field| {
+        let offset = quote! {
+            #own_before #(+ <#flattened_before as #crate_path::VisitFields>::FIELD_COUNT)*
+        };
+        let member = &field.member;
+        let ty = &field.ty;
+        if field.flatten {
+            flattened_before.push(ty.clone());
+            quote! {
+                let start = #offset;
+                let end = start + <#ty as #crate_path::VisitFields>::FIELD_COUNT;
+                for mut change in #crate_path::FieldDiff::diff(&self.#member, &new.#member) {
+                    if let Some(meta) = fields[start..end].iter().find(|m| m.name == change.meta.name) {
+                        change.meta = meta;
+                    }
+                    changes.push(change);
+                }
+            }
+        } else {
+            own_before += 1;
+            quote! {
+                if self.#member != new.#member {
+                    changes.push(#crate_path::FieldChange::new(
+                        &fields[#offset],
+                        &self.#member,
+                        &new.#member,
+                    ));
+                }
+            }
+        }
+    });
+    let compares: Vec<_> = compares.collect();
+
+    quote! {
+        impl #impl_generics #crate_path::FieldDiff for #struct_name #ty_generics #where_clause {
+            fn diff(&self, new: &Self) -> Vec<#crate_path::FieldChange> {
+                let fields = <Self as #crate_path::VisitFields>::FIELDS;
+                let mut changes = Vec::new();
+                #(#compares)*
+                changes
+            }
+        }
+    }
+}
+
+/// Implements `Categorized` as `Record` for a deriving struct, exposing its
+/// fields as the schema.
+pub fn generate_record(
+    struct_name: &Ident,
+    generics: &Generics,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #crate_path::Categorized for #struct_name #ty_generics #where_clause {
+            type Category = #crate_path::Record;
+            const SCHEMA: Option<#crate_path::Schema> =
+                Some(#crate_path::Schema::of::<Self>());
+        }
+    }
+}
+
+/// Implements `Categorized` for a `#[field_kinds(transparent)]` wrapper by
+/// forwarding to the wrapped field's type.
+pub fn generate_transparent(
+    struct_name: &Ident,
+    generics: &Generics,
+    field: &ParsedField,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    // No extra bound needed: the `VisitFields` impl already requires the
+    // field type to be `Categorized` under the struct's own bounds.
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let category = category_type(field, &module_name(struct_name), crate_path);
+    let inner = nested_const(field, crate_path, false);
+    let key = nested_const(field, crate_path, true);
+    let numeric_class = categorized_const(field, crate_path, "NUMERIC_CLASS");
+    let numeric_kind = categorized_const(field, crate_path, "NUMERIC_KIND");
+    let schema = categorized_const(field, crate_path, "SCHEMA");
+
+    quote! {
+        impl #impl_generics #crate_path::Categorized for #struct_name #ty_generics #where_clause {
+            type Category = #category;
+            const INNER: Option<&'static #crate_path::NestedCategory> = #inner;
+            const KEY: Option<&'static #crate_path::NestedCategory> = #key;
+            const NUMERIC_CLASS: Option<#crate_path::NumericClass> = #numeric_class;
+            const NUMERIC_KIND: Option<#crate_path::NumericKind> = #numeric_kind;
+            const SCHEMA: Option<#crate_path::Schema> = #schema;
+        }
+    }
+}
+
+/// The field's category marker type: the `#[field_kinds(category)]`
+/// override, the generated fallback marker for probed fields, otherwise
+/// `<T as Categorized>::Category`.
+fn category_type(
+    field: &ParsedField,
+    mod_name: &Ident,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let field_type = &field.ty;
+    match &field.category {
+        Some(CategoryOverride::Builtin(marker)) => {
+            quote! { #crate_path::#marker }
+        }
+        Some(CategoryOverride::Path(path)) => quote! { #path },
+        None if field.unknown_fallback => {
+            let marker = field.category_marker_name();
+            quote! { #mod_name::#marker }
+        }
+        None => quote! { <#field_type as #crate_path::Categorized>::Category },
+    }
+}
+
+/// The field's `Category` value.
+fn category_value(
+    field: &ParsedField,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let field_type = &field.ty;
+    match &field.category {
+        Some(CategoryOverride::Builtin(marker)) => quote! {
+            <#crate_path::#marker as #crate_path::TypeCategory>::CATEGORY
+        },
+        Some(CategoryOverride::Path(path)) => quote! {
+            <#path as #crate_path::TypeCategory>::CATEGORY
+        },
+        None if field.unknown_fallback => {
+            probe_tokens(field_type, crate_path, "CATEGORY")
+        }
+        None => quote! {
+            <<#field_type as #crate_path::Categorized>::Category as #crate_path::TypeCategory>::CATEGORY
+        },
+    }
+}
+
+/// One of the field type's optional `Categorized` constants
+/// (`NUMERIC_CLASS`, ...). Probed in fallback mode and for `identifier` and
+/// `binary` fields; `None` for other overridden fields, as their type need
+/// not implement `Categorized`.
+fn categorized_const(
+    field: &ParsedField,
+    crate_path: &TokenStream,
+    item: &str,
+) -> TokenStream {
+    if field.probes_category() || field.keeps_type_metadata() {
+        return probe_tokens(&field.ty, crate_path, item);
+    }
+    if field.category.is_some() {
+        return quote! { None };
+    }
+    let field_type = &field.ty;
+    let item = format_ident!("{}", item);
+    quote! { <#field_type as #crate_path::Categorized>::#item }
+}
+
+/// The field type's element (or, with `key`, key) category; `None` for
+/// overridden fields other than `identifier` and `binary`.
+fn nested_const(
+    field: &ParsedField,
+    crate_path: &TokenStream,
+    key: bool,
+) -> TokenStream {
+    if field.category.is_some() && !field.keeps_type_metadata() {
+        return quote! { None };
+    }
+    nested_tokens(&field.ty, key, field.generic, crate_path)
+}
+
+/// `Option<&'static NestedCategory>` for the element or key of `ty`.
+///
+/// Containers are matched by shape, so their elements need not implement
+/// `Categorized`: an element without an impl becomes an `unknown` node. The
+/// node is built in a nested `const`, which can't name generic parameters,
+/// so generic element types read `Probe::NESTED` instead and only get a
+/// node if they're bounded by `Categorized`.
+fn nested_tokens(
+    ty: &Type,
+    key: bool,
+    generic: bool,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let element = match (TypeShape::of(ty), key) {
+        (TypeShape::Element(element) | TypeShape::Map(_, element), false)
+        | (TypeShape::Map(element, _), true) => element,
+        (TypeShape::Element(_), true) => return quote! { None },
+        (TypeShape::Wrapper(inner), _) => {
+            return nested_tokens(inner, key, generic, crate_path);
+        }
+        (TypeShape::Other, _) => {
+            let item = if key { "KEY" } else { "INNER" };
+            return probe_tokens(ty, crate_path, item);
+        }
+    };
+    if generic {
+        return probe_tokens(element, crate_path, "NESTED");
+    }
+    let inner = nested_tokens(element, false, false, crate_path);
+    let key = nested_tokens(element, true, false, crate_path);
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use #crate_path::__private::Fallback as _;
+            const NODE: #crate_path::NestedCategory =
+                #crate_path::__private::Probe::<#element>::NODE
+                    .with_nested(#inner, #key);
+            Some(&NODE)
+        }
+    }
+}
+
+/// Reads a `Probe` constant, which falls back to `Unknown` when the type
+/// doesn't implement `Categorized`.
+fn probe_tokens(
+    ty: &Type,
+    crate_path: &TokenStream,
+    item: &str,
+) -> TokenStream {
+    let item = format_ident!("{}", item);
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use #crate_path::__private::Fallback as _;
+            #crate_path::__private::Probe::<#ty>::#item
+        }
+    }
+}
+
 fn module_name(struct_name: &Ident) -> Ident {
     use convert_case::{Case, Casing};
     format_ident!("{}_fields", struct_name.to_string().to_case(Case::Snake))
@@ -76,20 +609,45 @@ fn phantom_data_type(generics: &Generics) -> TokenStream {
     }
 }
 
-fn tags_tokens(tags: &[String]) -> TokenStream {
-    if tags.is_empty() {
+/// `&'static [&'static str]` literal for tags, aliases, etc.
+fn str_slice_tokens(items: &[String]) -> TokenStream {
+    if items.is_empty() {
         quote! { &[] }
     } else {
-        quote! { &[#(#tags),*] }
+        quote! { &[#(#items),*] }
     }
 }
 
+/// Builds the `&'static [(&'static str, TagValue)]` literal for key/value tags.
+fn tag_values_tokens(
+    values: &[(String, TagValue)],
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let entries = values.iter().map(|(key, value)| {
+        let value = match value {
+            TagValue::Str(s) => quote! { Str(#s) },
+            TagValue::Int(i) => quote! { Int(#i) },
+            TagValue::Float(f) => quote! { Float(#f) },
+            TagValue::Bool(b) => quote! { Bool(#b) },
+        };
+        quote! { (#key, #crate_path::TagValue::#value) }
+    });
+    quote! { &[#(#entries),*] }
+}
+
+/// Generates the marker types and their `FieldInfo` impls.
+///
+/// Markers live in the hidden module, but the impls are emitted next to the
+/// struct so field types resolve in the user's scope rather than to a marker
+/// of the same name (`email: Email` has a marker called `Email`).
 fn generate_field_types(
+    mod_name: &Ident,
+    vis: &TokenStream,
     fields: &[&ParsedField],
-    rename_all: Option<RenameRule>,
+    rename_all: RenameAll,
     generics: &Generics,
-    _crate_path: &TokenStream,
-) -> TokenStream {
+    crate_path: &TokenStream,
+) -> (TokenStream, TokenStream) {
     let has_generics = has_phantom_params(generics);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -98,79 +656,311 @@ fn generate_field_types(
         .map(|field| {
             let type_name = field.marker_type_name();
             let field_type = &field.ty;
-            let field_name_str = field.ident.to_string();
+            let field_name_str = field.name();
             let serialized_name = field.serialized_name(rename_all);
+            let deserialize_name = field.deserialize_name(rename_all);
 
-            let tags_tokens = tags_tokens(&field.tags);
+            let tags_tokens = str_slice_tokens(&field.tags.names);
+            let tag_values = tag_values_tokens(&field.tags.values, crate_path);
+            let category = category_type(field, mod_name, crate_path);
+            let (category_marker, category_impl) =
+                fallback_category_tokens(field, mod_name, vis, crate_path);
 
-            if has_generics {
-                let phantom_type = phantom_data_type(generics);
+            let info_body = quote! {
+                const NAME: &'static str = #field_name_str;
+                const SERIALIZED_NAME: &'static str = #serialized_name;
+                const DESERIALIZE_NAME: &'static str = #deserialize_name;
+                const CATEGORY_NAME: &'static str = <#category as #crate_path::TypeCategory>::NAME;
+                const TAGS: &'static [&'static str] = #tags_tokens;
+                const TAG_VALUES: &'static [(&'static str, #crate_path::TagValue)] = #tag_values;
 
-                quote! {
-                    pub struct #type_name #impl_generics (#phantom_type) #where_clause;
+                type Value = #field_type;
+                type Category = #category;
+            };
 
-                    impl #impl_generics FieldInfo for #type_name #ty_generics #where_clause {
-                        const NAME: &'static str = #field_name_str;
-                        const SERIALIZED_NAME: &'static str = #serialized_name;
-                        const CATEGORY_NAME: &'static str = <<#field_type as Categorized>::Category as TypeCategory>::NAME;
-                        const TAGS: &'static [&'static str] = #tags_tokens;
+            if has_generics {
+                let phantom_type = phantom_data_type(generics);
 
-                        type Value = #field_type;
-                        type Category = <#field_type as Categorized>::Category;
+                let marker = quote! {
+                    #vis struct #type_name #impl_generics (#phantom_type) #where_clause;
+                    #category_marker
+                };
+                let info_impl = quote! {
+                    impl #impl_generics #crate_path::FieldInfo for #mod_name::#type_name #ty_generics #where_clause {
+                        #info_body
                     }
-                }
+                    #category_impl
+                };
+                (marker, info_impl)
             } else {
-                quote! {
+                let marker = quote! {
                     #[derive(Debug, Clone, Copy)]
-                    pub struct #type_name;
-
-                    impl FieldInfo for #type_name {
-                        const NAME: &'static str = #field_name_str;
-                        const SERIALIZED_NAME: &'static str = #serialized_name;
-                        const CATEGORY_NAME: &'static str = <<#field_type as Categorized>::Category as TypeCategory>::NAME;
-                        const TAGS: &'static [&'static str] = #tags_tokens;
-
-                        type Value = #field_type;
-                        type Category = <#field_type as Categorized>::Category;
+                    #vis struct #type_name;
+                    #category_marker
+                };
+                let info_impl = quote! {
+                    impl #crate_path::FieldInfo for #mod_name::#type_name {
+                        #info_body
                     }
-                }
+                    #category_impl
+                };
+                (marker, info_impl)
             }
         })
-        .collect()
+        .unzip()
+}
+
+/// Visibility of the markers inside the hidden module, matching the
+/// deriving type's so that `FieldInfo::Value` can name field types that are
+/// only as visible as the type itself.
+fn marker_visibility(vis: &Visibility) -> TokenStream {
+    match vis {
+        Visibility::Public(_) => quote! { pub },
+        Visibility::Inherited => quote! { pub(super) },
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            let mut segments = path.segments.iter();
+            match segments.next() {
+                Some(first) if first.ident == "crate" => quote! { #vis },
+                Some(first) if first.ident == "self" => {
+                    let rest = segments.map(|s| &s.ident);
+                    quote! { pub(in super #(::#rest)*) }
+                }
+                _ => quote! { pub(in super::#path) },
+            }
+        }
+    }
+}
+
+/// For probed fields, a category marker whose name is the probed category,
+/// used as `FieldInfo::Category` since the type can't be picked at compile
+/// time. Empty for other fields.
+fn fallback_category_tokens(
+    field: &ParsedField,
+    mod_name: &Ident,
+    vis: &TokenStream,
+    crate_path: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    if !field.probes_category() {
+        return (TokenStream::new(), TokenStream::new());
+    }
+    let marker = field.category_marker_name();
+    let category = probe_tokens(&field.ty, crate_path, "CATEGORY");
+    (
+        quote! {
+            #[derive(Debug, Clone, Copy)]
+            #vis struct #marker;
+        },
+        quote! {
+            impl #crate_path::TypeCategory for #mod_name::#marker {
+                const NAME: &'static str = #category.name();
+            }
+        },
+    )
 }
 
 fn generate_visit_impl(
     struct_name: &Ident,
     generics: &Generics,
     fields: &[&ParsedField],
-    rename_all: Option<RenameRule>,
+    container: &ParsedContainer,
+    rename_all: RenameAll,
     crate_path: &TokenStream,
 ) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let fields_slice = fields_slice_tokens(fields, rename_all, crate_path);
+    let type_meta = type_meta_tokens(struct_name, container, crate_path);
+
+    quote! {
+        impl #impl_generics #crate_path::VisitFields for #struct_name #ty_generics #where_clause {
+            const FIELDS: &'static [#crate_path::FieldMeta] = #fields_slice;
+            const TYPE: #crate_path::TypeMeta = #type_meta;
+        }
+    }
+}
+
+/// Builds the `TypeMeta` expression for the deriving struct. Container
+/// `rename_all` applies to fields only, so names default to the identifier.
+fn type_meta_tokens(
+    struct_name: &Ident,
+    container: &ParsedContainer,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let name = struct_name.to_string();
+    let serialized_name = container
+        .rename
+        .serialize
+        .clone()
+        .unwrap_or_else(|| name.clone());
+    let deserialize_name = container
+        .rename
+        .deserialize
+        .clone()
+        .unwrap_or_else(|| name.clone());
+    let tags = str_slice_tokens(&container.tags.names);
+    let tag_values = tag_values_tokens(&container.tags.values, crate_path);
+    let exclude_serde_skipped = container.exclude_serde_skipped;
+    quote! {
+        #crate_path::TypeMeta::new(
+            #name,
+            #serialized_name,
+            ::core::module_path!(),
+            #tags,
+        )
+        .with_deserialize_name(#deserialize_name)
+        .with_tag_values(#tag_values)
+        .with_exclude_serde_skipped(#exclude_serde_skipped)
+    }
+}
 
-    let field_metas: Vec<_> = fields
+/// Builds the `&'static [FieldMeta]` expression for a list of fields.
+///
+/// Without `#[serde(flatten)]` this is a plain slice literal. Otherwise the
+/// own fields and the flattened types' `FIELDS` are concatenated in a
+/// nested `const` block.
+fn fields_slice_tokens(
+    fields: &[&ParsedField],
+    rename_all: RenameAll,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    if !fields.iter().any(|f| f.flatten) {
+        let field_metas = fields
+            .iter()
+            .map(|f| field_meta_tokens(f, rename_all, crate_path));
+        return quote! { &[#(#field_metas),*] };
+    }
+
+    let mut parts = Vec::new();
+    let mut own = Vec::new();
+    for field in fields {
+        if field.flatten {
+            if !own.is_empty() {
+                parts.push(quote! { (&[#(#own),*], None) });
+                own.clear();
+            }
+            let field_type = &field.ty;
+            let name = field.name();
+            parts.push(quote! {
+                (<#field_type as #crate_path::VisitFields>::FIELDS, Some(#name))
+            });
+        } else {
+            own.push(field_meta_tokens(field, rename_all, crate_path));
+        }
+    }
+    if !own.is_empty() {
+        parts.push(quote! { (&[#(#own),*], None) });
+    }
+
+    quote! {
+        {
+            const PARTS: &[(&[#crate_path::FieldMeta], Option<&str>)] = &[
+                #(#parts),*
+            ];
+            const LEN: usize = #crate_path::__private::flattened_len(PARTS);
+            const FLAT: [#crate_path::FieldMeta; LEN] =
+                #crate_path::__private::flatten_fields(PARTS);
+            &FLAT
+        }
+    }
+}
+
+fn field_meta_tokens(
+    field: &ParsedField,
+    rename_all: RenameAll,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let name = field.name();
+    let serialized_name = field.serialized_name(rename_all);
+    let deserialize_name = field.deserialize_name(rename_all);
+    let category = category_value(field, crate_path);
+    let inner = nested_const(field, crate_path, false);
+    let key = nested_const(field, crate_path, true);
+    let numeric_class = categorized_const(field, crate_path, "NUMERIC_CLASS");
+    let numeric_kind = categorized_const(field, crate_path, "NUMERIC_KIND");
+    let schema = categorized_const(field, crate_path, "SCHEMA");
+    let tags_tokens = str_slice_tokens(&field.tags.names);
+    let tag_values = tag_values_tokens(&field.tags.values, crate_path);
+    let aliases_tokens = str_slice_tokens(&field.aliases);
+    let index = field.index;
+    let skip_serializing = field.skip_serializing;
+    let skip_deserializing = field.skip_deserializing;
+    let flattened_from = field
+        .flatten_opaque
+        .then(|| quote! { .with_flattened_from(#name) });
+    let parsed = field.option_inner().unwrap_or(&field.ty);
+
+    quote! {
+        #crate_path::FieldMeta::new(
+            #name,
+            #serialized_name,
+            #category,
+            #tags_tokens,
+        )
+        .with_index(#index)
+        .with_deserialize_name(#deserialize_name)
+        .with_aliases(#aliases_tokens)
+        .with_tag_values(#tag_values)
+        .with_nested(#inner, #key)
+        .with_numeric_class(#numeric_class)
+        .with_numeric_kind(#numeric_kind)
+        .with_schema(#schema)
+        .with_serde_skip(#skip_serializing, #skip_deserializing)
+        .with_settable_from_str({
+            #[allow(unused_imports)]
+            use #crate_path::__private::ParseFallback as _;
+            #crate_path::__private::ParseProbe::<#parsed>::SUPPORTED
+        })
+        #flattened_from
+    }
+}
+
+pub fn generate_variants(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: &[ParsedVariant],
+    rename_all: RenameAll,
+    rename_all_fields: RenameAll,
+    crate_path: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let variant_metas: Vec<_> = variants
         .iter()
-        .map(|f| {
-            let name = f.ident.to_string();
-            let serialized_name = f.serialized_name(rename_all);
-            let field_type = &f.ty;
-            let tags_tokens = tags_tokens(&f.tags);
+        .filter(|v| !v.skip)
+        .map(|v| {
+            let name = v.ident.to_string();
+            let serialized_name = v.serialized_name(rename_all);
+            let deserialize_name = v.deserialize_name(rename_all);
+            let kind = match v.shape {
+                VariantShape::Unit => quote! { Unit },
+                VariantShape::Tuple => quote! { Tuple },
+                VariantShape::Struct => quote! { Struct },
+            };
+            let fields_rename_all = v.rename_all.or(rename_all_fields);
+            let active_fields: Vec<_> =
+                v.fields.iter().filter(|f| !f.skip).collect();
+            let fields_slice = fields_slice_tokens(
+                &active_fields,
+                fields_rename_all,
+                crate_path,
+            );
 
             quote! {
-                #crate_path::FieldMeta::new(
+                #crate_path::VariantMeta::new(
                     #name,
                     #serialized_name,
-                    <<#field_type as #crate_path::Categorized>::Category as #crate_path::TypeCategory>::CATEGORY,
-                    #tags_tokens,
+                    #crate_path::VariantKind::#kind,
+                    #fields_slice,
                 )
+                .with_deserialize_name(#deserialize_name)
             }
         })
         .collect();
 
     quote! {
-        impl #impl_generics #crate_path::VisitFields for #struct_name #ty_generics #where_clause {
-            const FIELDS: &'static [#crate_path::FieldMeta] = &[
-                #(#field_metas),*
+        impl #impl_generics #crate_path::VisitVariants for #enum_name #ty_generics #where_clause {
+            const VARIANTS: &'static [#crate_path::VariantMeta] = &[
+                #(#variant_metas),*
             ];
         }
     }
@@ -22,17 +22,98 @@ fn derive_impl(
 ) -> syn::Result<proc_macro2::TokenStream> {
     let struct_name = &input.ident;
     let generics = &input.generics;
-    let rename_all = parse::parse_rename_all(&input.attrs);
-    let fields = parse::parse_fields(input)?;
+    parse::validate_container(input)?;
+    let rename_all = parse::parse_rename_all(&input.attrs)?;
     let crate_path = resolve_crate_path();
+    parse::validate_unknown_fallback(input)?;
+
+    if let syn::Data::Enum(_) = &input.data {
+        parse::parse_transparent(input, &[])?;
+    }
 
-    Ok(generate::generate_all(
+    if let syn::Data::Enum(data) = &input.data {
+        let variants = parse::parse_variants(input, data)?;
+        for variant in &variants {
+            parse::validate_flatten(generics, &variant.fields)?;
+        }
+        let rename_all_fields = parse::parse_rename_all_fields(&input.attrs)?;
+        return Ok(generate::generate_variants(
+            struct_name,
+            generics,
+            &variants,
+            rename_all,
+            rename_all_fields,
+            &crate_path,
+        ));
+    }
+
+    let fields = parse::parse_fields(input)?;
+    parse::validate_flatten(generics, &fields)?;
+    let transparent = parse::parse_transparent(input, &fields)?;
+    let container = parse::parse_container(&input.attrs)?;
+
+    let mut tokens = generate::generate_all(
         struct_name,
+        &input.vis,
         generics,
         &fields,
+        &container,
         rename_all,
         &crate_path,
-    ))
+    );
+    tokens.extend(generate::generate_set_from_str(
+        struct_name,
+        generics,
+        &fields,
+        rename_all,
+        &crate_path,
+    ));
+    if parse::parse_env(&input.attrs) {
+        tokens.extend(generate::generate_from_env(
+            struct_name,
+            generics,
+            &fields,
+            &crate_path,
+        ));
+    }
+    if parse::parse_diff(&input.attrs) {
+        tokens.extend(generate::generate_diff(
+            struct_name,
+            generics,
+            &fields,
+            &crate_path,
+        ));
+    }
+    // `dyn Any` needs `'static` values, which borrowed fields aren't.
+    if generics.lifetimes().next().is_none() {
+        tokens.extend(generate::generate_field_access(
+            struct_name,
+            generics,
+            &fields,
+            &crate_path,
+        ));
+        tokens.extend(generate::generate_visit_values(
+            struct_name,
+            generics,
+            &fields,
+            &crate_path,
+        ));
+    }
+    if let Some(field) = transparent {
+        tokens.extend(generate::generate_transparent(
+            struct_name,
+            generics,
+            field,
+            &crate_path,
+        ));
+    } else if !parse::parse_skip_categorized(&input.attrs) {
+        tokens.extend(generate::generate_record(
+            struct_name,
+            generics,
+            &crate_path,
+        ));
+    }
+    Ok(tokens)
 }
 
 fn resolve_crate_path() -> proc_macro2::TokenStream {
@@ -1,114 +1,696 @@
-use crate::field::{ParsedField, RenameRule};
+use crate::field::{
+    CategoryOverride, Directional, ParsedContainer, ParsedField, ParsedVariant,
+    RenameAll, RenameRule, TagValue, Tags, VariantShape,
+};
 use convert_case::Case;
-use syn::{Attribute, DeriveInput, Field, Ident, Lit, LitStr, Result};
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, DataEnum, DeriveInput, Expr, Field, GenericParam, Generics,
+    Ident, Index, Lit, LitStr, Member, Result, Token, Type, UnOp, Variant,
+};
 
-/// Parses `rename_all` from `#[serde(rename_all = "...")]`
-pub fn parse_rename_all(attrs: &[Attribute]) -> Option<RenameRule<'_>> {
+/// Parses `rename_all` from `#[serde(rename_all = "...")]` or
+/// `#[serde(rename_all(serialize = "...", deserialize = "..."))]`
+pub fn parse_rename_all(attrs: &[Attribute]) -> Result<RenameAll> {
+    parse_directional(attrs, "rename_all", rename_rule)
+}
+
+/// Parses `rename_all_fields` from `#[serde(rename_all_fields = "...")]`
+pub fn parse_rename_all_fields(attrs: &[Attribute]) -> Result<RenameAll> {
+    parse_directional(attrs, "rename_all_fields", rename_rule)
+}
+
+/// Parses the container's own `rename`, `#[field_tags(...)]` and
+/// `#[field_kinds(exclude_serde_skipped)]`
+pub fn parse_container(attrs: &[Attribute]) -> Result<ParsedContainer> {
+    Ok(ParsedContainer {
+        rename: parse_rename(attrs)?,
+        tags: parse_tags(attrs)?,
+        exclude_serde_skipped: parse_flag(
+            attrs,
+            "field_kinds",
+            "exclude_serde_skipped",
+        ),
+    })
+}
+
+/// Parses `rename` from `#[serde(rename = "...")]` or
+/// `#[serde(rename(serialize = "...", deserialize = "..."))]`
+fn parse_rename(attrs: &[Attribute]) -> Result<Directional<String>> {
+    parse_directional(attrs, "rename", |value| Ok(value.value()))
+}
+
+/// Collects every `#[serde(alias = "...")]`
+fn parse_aliases(attrs: &[Attribute]) -> Vec<String> {
+    let mut aliases = Vec::new();
     for attr in attrs {
         if !attr.path().is_ident("serde") {
             continue;
         }
 
-        let mut result = None;
         let _ = attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("rename_all") {
+            if meta.path.is_ident("alias") {
                 let value: LitStr = meta.value()?.parse()?;
-                result = string_to_rename_rule(&value.value());
+                aliases.push(value.value());
+                Ok(())
+            } else {
+                skip_meta_value(&meta)
             }
-            Ok(())
         });
+    }
+    aliases
+}
 
-        if result.is_some() {
-            return result;
+/// Parses a serde item that accepts either `key = "..."` for both
+/// directions or `key(serialize = "...", deserialize = "...")`.
+/// The first attribute setting a direction wins.
+fn parse_directional<T: Clone>(
+    attrs: &[Attribute],
+    key: &str,
+    convert: impl Fn(&LitStr) -> Result<T>,
+) -> Result<Directional<T>> {
+    let mut result = Directional::default();
+    for attr in attrs {
+        // A bare `#[serde]` is serde's to reject.
+        if !attr.path().is_ident("serde")
+            || !matches!(attr.meta, syn::Meta::List(_))
+        {
+            continue;
         }
+
+        let mut found = Directional::default();
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(key) {
+                return skip_meta_value(&meta);
+            }
+            if meta.input.peek(Token![=]) {
+                let value: LitStr = meta.value()?.parse()?;
+                let converted = convert(&value)?;
+                found.serialize = Some(converted.clone());
+                found.deserialize = Some(converted);
+                return Ok(());
+            }
+            meta.parse_nested_meta(|inner| {
+                let value: LitStr = inner.value()?.parse()?;
+                if inner.path.is_ident("serialize") {
+                    found.serialize = Some(convert(&value)?);
+                } else if inner.path.is_ident("deserialize") {
+                    found.deserialize = Some(convert(&value)?);
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        &inner.path,
+                        format!(
+                            "expected `serialize` or `deserialize` in `{key}(...)`"
+                        ),
+                    ));
+                }
+                Ok(())
+            })
+        })?;
+
+        result = result.or(found);
     }
-    None
+    Ok(result)
 }
 
 /// Parses all fields of the struct
 pub fn parse_fields(input: &DeriveInput) -> Result<Vec<ParsedField>> {
-    let fields = extract_named_fields(input)?;
-    Ok(fields.iter().map(|f| parse_single_field(f)).collect())
+    let fields = extract_fields(input)?;
+    let defaults = FieldDefaults::parse(input);
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, f)| parse_single_field(index, f, defaults))
+        .collect()
+}
+
+/// Parses all variants of the enum
+pub fn parse_variants(
+    input: &DeriveInput,
+    data: &DataEnum,
+) -> Result<Vec<ParsedVariant>> {
+    let defaults = FieldDefaults::parse(input);
+    data.variants
+        .iter()
+        .map(|v| parse_single_variant(v, defaults))
+        .collect()
+}
+
+/// Container-level `#[field_kinds(...)]` opt-ins applied to every field,
+/// and the container's generics
+#[derive(Clone, Copy)]
+struct FieldDefaults<'a> {
+    exclude_serde_skipped: bool,
+    unknown_fallback: bool,
+    generics: &'a Generics,
+}
+
+impl<'a> FieldDefaults<'a> {
+    fn parse(input: &'a DeriveInput) -> Self {
+        let attrs = &input.attrs;
+        Self {
+            exclude_serde_skipped: parse_flag(
+                attrs,
+                "field_kinds",
+                "exclude_serde_skipped",
+            ),
+            unknown_fallback: parse_flag(
+                attrs,
+                "field_kinds",
+                "unknown_fallback",
+            ),
+            generics: &input.generics,
+        }
+    }
+}
+
+fn parse_single_variant(
+    variant: &Variant,
+    defaults: FieldDefaults<'_>,
+) -> Result<ParsedVariant> {
+    let shape = match &variant.fields {
+        syn::Fields::Unit => VariantShape::Unit,
+        syn::Fields::Unnamed(_) => VariantShape::Tuple,
+        syn::Fields::Named(_) => VariantShape::Struct,
+    };
+
+    validate_field_kinds(&variant.attrs, VARIANT_KEYS)?;
+    reject_field_tags(&variant.attrs, "enum variants")?;
+    Ok(ParsedVariant {
+        ident: variant.ident.clone(),
+        shape,
+        rename: parse_rename(&variant.attrs)?,
+        rename_all: parse_rename_all(&variant.attrs)?,
+        fields: variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, f)| parse_single_field(index, f, defaults))
+            .collect::<Result<_>>()?,
+        skip: parse_flag(&variant.attrs, "field_kinds", "skip"),
+    })
 }
 
-fn extract_named_fields(input: &DeriveInput) -> Result<Vec<&Field>> {
+fn extract_fields(input: &DeriveInput) -> Result<Vec<&Field>> {
     match &input.data {
-        syn::Data::Struct(data) => match &data.fields {
-            syn::Fields::Named(fields) => Ok(fields.named.iter().collect()),
-            _ => Err(syn::Error::new_spanned(
-                input,
-                "FieldKinds only supports structs with named fields",
-            )),
-        },
+        syn::Data::Struct(data) => Ok(data.fields.iter().collect()),
         _ => Err(syn::Error::new_spanned(
             input,
-            "FieldKinds only supports structs",
+            "FieldKinds only supports structs and enums",
         )),
     }
 }
 
-fn parse_single_field(field: &Field) -> ParsedField {
-    ParsedField {
-        ident: field.ident.clone().unwrap(),
+fn parse_single_field(
+    index: usize,
+    field: &Field,
+    defaults: FieldDefaults<'_>,
+) -> Result<ParsedField> {
+    let member = field
+        .ident
+        .clone()
+        .map_or_else(|| Member::Unnamed(Index::from(index)), Member::Named);
+
+    validate_field_kinds(&field.attrs, FIELD_KEYS)?;
+    let serde_skip = parse_flag(&field.attrs, "serde", "skip");
+    let skip_serializing =
+        serde_skip || parse_flag(&field.attrs, "serde", "skip_serializing");
+    let skip_deserializing =
+        serde_skip || parse_flag(&field.attrs, "serde", "skip_deserializing");
+    let excluded = defaults.exclude_serde_skipped
+        && skip_serializing
+        && skip_deserializing;
+    let flatten = parse_flag(&field.attrs, "serde", "flatten");
+    let opaque = is_opaque_flatten(&field.ty);
+
+    Ok(ParsedField {
+        member,
+        index,
         ty: field.ty.clone(),
-        rename: parse_field_rename(field),
-        tags: parse_field_tags(field),
-        skip: parse_field_skip(field),
+        rename: parse_rename(&field.attrs)?,
+        aliases: parse_aliases(&field.attrs),
+        tags: parse_tags(&field.attrs)?,
+        category: parse_category(&field.attrs)?,
+        unknown_fallback: defaults.unknown_fallback,
+        skip: excluded || parse_flag(&field.attrs, "field_kinds", "skip"),
+        flatten: flatten && !opaque,
+        flatten_opaque: flatten && opaque,
+        skip_serializing,
+        skip_deserializing,
+        generic: names_generics(&field.ty, defaults.generics),
+    })
+}
+
+/// Whether `ty` names any of the generic parameters (including lifetimes),
+/// compared by identifier.
+fn names_generics(ty: &Type, generics: &Generics) -> bool {
+    fn walk(tokens: proc_macro2::TokenStream, names: &[String]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => {
+                ident == "Self" || names.iter().any(|name| ident == name)
+            }
+            TokenTree::Group(group) => walk(group.stream(), names),
+            _ => false,
+        })
     }
+
+    let names: Vec<_> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(lt) => lt.lifetime.ident.to_string(),
+            GenericParam::Type(tp) => tp.ident.to_string(),
+            GenericParam::Const(cp) => cp.ident.to_string(),
+        })
+        .collect();
+    walk(ty.to_token_stream(), &names)
 }
 
-fn parse_field_rename(field: &Field) -> Option<String> {
-    for attr in &field.attrs {
+/// Parses `#[field_kinds(category = "numeric")]` (built-in category),
+/// `#[field_kinds(category = path::ToMarker)]` (custom `TypeCategory`) or
+/// the `#[field_kinds(identifier)]` / `#[field_kinds(binary)]` shorthands.
+/// Without any of these, `#[serde(with = "serde_bytes")]` implies binary.
+fn parse_category(attrs: &[Attribute]) -> Result<Option<CategoryOverride>> {
+    let mut category = None;
+    for attr in attrs {
+        if !attr.path().is_ident("field_kinds") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("category") {
+                let value = meta.value()?;
+                if value.peek(LitStr) {
+                    let name: LitStr = value.parse()?;
+                    builtin_category(&name)?
+                } else {
+                    CategoryOverride::Path(value.parse()?)
+                }
+            } else if meta.path.is_ident("identifier") {
+                CategoryOverride::Builtin(Ident::new(
+                    "Identifier",
+                    meta.path.span(),
+                ))
+            } else if meta.path.is_ident("binary") {
+                CategoryOverride::Builtin(Ident::new(
+                    "Binary",
+                    meta.path.span(),
+                ))
+            } else {
+                return skip_meta_value(&meta);
+            };
+            if category.is_some() {
+                return Err(meta.error(
+                    "the category is already set for this field; use only \
+                     one of `category = ...`, `identifier` and `binary`",
+                ));
+            }
+            category = Some(parsed);
+            Ok(())
+        })?;
+    }
+    if category.is_none() && is_serde_bytes(attrs) {
+        category = Some(CategoryOverride::Builtin(Ident::new(
+            "Binary",
+            proc_macro2::Span::call_site(),
+        )));
+    }
+    Ok(category)
+}
+
+/// Checks for `#[serde(with = "serde_bytes")]`
+fn is_serde_bytes(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+    for attr in attrs {
         if !attr.path().is_ident("serde") {
             continue;
         }
 
-        let mut result = None;
         let _ = attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("rename") {
+            if meta.path.is_ident("with") {
                 let value: LitStr = meta.value()?.parse()?;
-                result = Some(value.value());
+                found |= value.value() == "serde_bytes";
+                Ok(())
+            } else {
+                skip_meta_value(&meta)
             }
-            Ok(())
         });
+    }
+    found
+}
 
-        if result.is_some() {
-            return result;
+fn builtin_category(name: &LitStr) -> Result<CategoryOverride> {
+    let marker = match name.value().as_str() {
+        "numeric" => "Numeric",
+        "text" => "Text",
+        "bool" => "Bool",
+        "optional" => "Optional",
+        "collection" => "Collection",
+        "temporal" => "Temporal",
+        "identifier" => "Identifier",
+        "binary" => "Binary",
+        "record" => "Record",
+        "unknown" => "Unknown",
+        other => {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!(
+                    "unknown category `{other}`, expected one of: numeric, \
+                     text, bool, optional, collection, temporal, identifier, \
+                     binary, record, unknown, or a path to a `TypeCategory` \
+                     type"
+                ),
+            ));
         }
+    };
+    Ok(CategoryOverride::Builtin(Ident::new(marker, name.span())))
+}
+
+/// Parses the container-level `#[field_kinds(skip_categorized)]` opt-out of
+/// the generated `Categorized` impl, for types with a hand-written one
+pub fn parse_skip_categorized(attrs: &[Attribute]) -> bool {
+    parse_flag(attrs, "field_kinds", "skip_categorized")
+}
+
+/// Parses the container-level `#[field_kinds(env)]` opt-in to `FromEnv`
+pub fn parse_env(attrs: &[Attribute]) -> bool {
+    parse_flag(attrs, "field_kinds", "env")
+}
+
+/// Parses the container-level `#[field_kinds(diff)]` opt-in to `FieldDiff`
+pub fn parse_diff(attrs: &[Attribute]) -> bool {
+    parse_flag(attrs, "field_kinds", "diff")
+}
+
+/// Parses the container-level `#[field_kinds(transparent)]` opt-in and
+/// returns the wrapped field, which must be the only one not skipped.
+pub fn parse_transparent<'a>(
+    input: &DeriveInput,
+    fields: &'a [ParsedField],
+) -> Result<Option<&'a ParsedField>> {
+    if !parse_flag(&input.attrs, "field_kinds", "transparent") {
+        return Ok(None);
+    }
+    if !matches!(input.data, syn::Data::Struct(_)) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[field_kinds(transparent)] is only supported on structs",
+        ));
+    }
+
+    let mut active = fields.iter().filter(|f| {
+        let serde_skipped = f.skip_serializing && f.skip_deserializing;
+        !f.skip && !serde_skipped
+    });
+    match (active.next(), active.next()) {
+        (Some(field), None) => Ok(Some(field)),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[field_kinds(transparent)] requires exactly one non-skipped field",
+        )),
     }
-    None
 }
 
-fn parse_field_tags(field: &Field) -> Vec<String> {
-    let mut tags = Vec::new();
-    for attr in &field.attrs {
-        if attr.path().is_ident("field_tags")
-            && let Ok(args) = attr.parse_args_with(
-                syn::punctuated::Punctuated::<Lit, syn::Token![,]>::parse_terminated,
-            )
-        {
-            tags.extend(args.iter().filter_map(|lit| {
-                if let Lit::Str(s) = lit {
-                    Some(s.value())
+/// Rejects `#[field_kinds(unknown_fallback)]` on generic types: the probe
+/// only resolves correctly for concrete field types.
+pub fn validate_unknown_fallback(input: &DeriveInput) -> Result<()> {
+    if !parse_flag(&input.attrs, "field_kinds", "unknown_fallback") {
+        return Ok(());
+    }
+    input
+        .generics
+        .params
+        .first()
+        .map_or(Ok(()), |param| {
+            Err(syn::Error::new_spanned(
+                param,
+                "#[field_kinds(unknown_fallback)] is not supported on generic types",
+            ))
+        })
+}
+
+/// Rejects `#[serde(flatten)]` on generic types: the flattened slice is
+/// built in a nested `const`, which cannot refer to generic parameters.
+pub fn validate_flatten(
+    generics: &Generics,
+    fields: &[ParsedField],
+) -> Result<()> {
+    if generics.params.is_empty() {
+        return Ok(());
+    }
+    fields
+        .iter()
+        .find(|f| f.flatten && !f.skip)
+        .map_or(Ok(()), |field| {
+            Err(syn::Error::new_spanned(
+                &field.ty,
+                "#[serde(flatten)] is not supported on generic types",
+            ))
+        })
+}
+
+/// Whether a `#[serde(flatten)]` type is a catch-all map or an `Option`
+/// rather than a struct deriving `FieldKinds` (matched by name, like serde
+/// does), so its fields can't be spliced.
+fn is_opaque_flatten(ty: &Type) -> bool {
+    const OPAQUE: &[&str] =
+        &["HashMap", "BTreeMap", "IndexMap", "Map", "Value", "Option"];
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| OPAQUE.iter().any(|name| segment.ident == name))
+}
+
+/// Collects `#[field_tags(...)]`: bare string tags (`"pii"`) and key/value
+/// tags (`max_len = 64`, `unit = "ms"`).
+fn parse_tags(attrs: &[Attribute]) -> Result<Tags> {
+    let mut tags = Tags::default();
+    for attr in attrs {
+        if !attr.path().is_ident("field_tags") {
+            continue;
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            while !input.is_empty() {
+                if input.peek(Ident::peek_any) && input.peek2(Token![=]) {
+                    let key = Ident::parse_any(input)?;
+                    input.parse::<Token![=]>()?;
+                    let value: Expr = input.parse()?;
+                    tags.values
+                        .push((key.unraw().to_string(), tag_value(&value)?));
+                } else if input.peek(Ident::peek_any) {
+                    let ident = Ident::parse_any(input)?;
+                    return Err(syn::Error::new_spanned(
+                        &ident,
+                        format!(
+                            "tags must be string literals: use `\"{ident}\"`, \
+                             or `{ident} = ...` for a key/value tag"
+                        ),
+                    ));
                 } else {
-                    None
+                    match input.parse()? {
+                        Lit::Str(s) => tags.names.push(s.value()),
+                        lit => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected a string tag like `\"pii\"` \
+                                 or a key/value tag like `max_len = 64`",
+                            ));
+                        }
+                    }
                 }
-            }));
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(tags)
+}
+
+/// Converts a tag value: a string, integer, float or bool literal, with an
+/// optional minus sign for numbers.
+fn tag_value(expr: &Expr) -> Result<TagValue> {
+    let (lit, negative) = match expr {
+        Expr::Lit(expr) => (&expr.lit, false),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            match &*unary.expr {
+                Expr::Lit(expr) => (&expr.lit, true),
+                _ => return Err(unsupported_tag_value(expr)),
+            }
+        }
+        _ => return Err(unsupported_tag_value(expr)),
+    };
+
+    match lit {
+        Lit::Str(s) if !negative => Ok(TagValue::Str(s.value())),
+        Lit::Bool(b) if !negative => Ok(TagValue::Bool(b.value)),
+        Lit::Int(int) => {
+            let sign = if negative { "-" } else { "" };
+            format!("{sign}{}", int.base10_digits())
+                .parse()
+                .map(TagValue::Int)
+                .map_err(|_| {
+                    syn::Error::new_spanned(
+                        expr,
+                        "integer tag value must fit in i64",
+                    )
+                })
         }
+        Lit::Float(float) => {
+            let value: f64 = float.base10_parse()?;
+            let value = if negative { -value } else { value };
+            if value.is_finite() {
+                Ok(TagValue::Float(value))
+            } else {
+                Err(syn::Error::new_spanned(
+                    expr,
+                    "float tag value must be finite",
+                ))
+            }
+        }
+        _ => Err(unsupported_tag_value(expr)),
     }
-    tags
 }
 
-fn parse_field_skip(field: &Field) -> bool {
-    for attr in &field.attrs {
-        if attr.path().is_ident("field_kinds")
-            && let Ok(meta) = attr.parse_args::<Ident>()
-            && meta == "skip"
-        {
-            return true;
+fn unsupported_tag_value(expr: &Expr) -> syn::Error {
+    syn::Error::new_spanned(
+        expr,
+        "tag values must be string, integer, float or bool literals",
+    )
+}
+
+/// `#[field_kinds(...)]` keys accepted on structs.
+const STRUCT_KEYS: &[&str] = &[
+    "exclude_serde_skipped",
+    "transparent",
+    "unknown_fallback",
+    "skip_categorized",
+    "env",
+    "diff",
+];
+
+/// `#[field_kinds(...)]` keys accepted on enums. `transparent` is rejected
+/// later with a dedicated message.
+const ENUM_KEYS: &[&str] =
+    &["exclude_serde_skipped", "transparent", "unknown_fallback"];
+
+/// `#[field_kinds(...)]` keys accepted on variants.
+const VARIANT_KEYS: &[&str] = &["skip"];
+
+/// `#[field_kinds(...)]` keys accepted on fields.
+const FIELD_KEYS: &[&str] = &["skip", "category", "identifier", "binary"];
+
+/// Rejects unknown keys in the container's `#[field_kinds(...)]`.
+pub fn validate_container(input: &DeriveInput) -> Result<()> {
+    let keys = match input.data {
+        syn::Data::Enum(_) => {
+            reject_field_tags(&input.attrs, "enums")?;
+            ENUM_KEYS
+        }
+        _ => STRUCT_KEYS,
+    };
+    validate_field_kinds(&input.attrs, keys)
+}
+
+/// Rejects `#[field_tags(...)]` where no metadata records it (enums and
+/// their variants), rather than silently dropping the tags.
+fn reject_field_tags(attrs: &[Attribute], target: &str) -> Result<()> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("field_tags"))
+        .map_or(Ok(()), |attr| {
+            Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "#[field_tags] is not supported on {target}, only on fields"
+                ),
+            ))
+        })
+}
+
+/// Checks that every `#[field_kinds(...)]` item is one of `keys`, and that
+/// flags (every key but `category`) carry no value.
+fn validate_field_kinds(attrs: &[Attribute], keys: &[&str]) -> Result<()> {
+    for attr in attrs {
+        if !attr.path().is_ident("field_kinds") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            let Some(key) = keys.iter().find(|key| meta.path.is_ident(key))
+            else {
+                let name = meta.path.to_token_stream().to_string();
+                return Err(meta.error(format!(
+                    "unknown field_kinds attribute `{}`, expected one of: {}",
+                    name.replace(' ', ""),
+                    keys.join(", ")
+                )));
+            };
+            if *key == "category" {
+                skip_meta_value(&meta)
+            } else if meta.input.is_empty() || meta.input.peek(Token![,]) {
+                Ok(())
+            } else {
+                Err(meta.error(format!("`{key}` takes no value")))
+            }
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks for a bare flag such as `#[serde(flatten)]` or `#[field_kinds(skip)]`
+fn parse_flag(attrs: &[Attribute], attr_name: &str, name: &str) -> bool {
+    let mut found = false;
+    for attr in attrs {
+        if !attr.path().is_ident(attr_name) {
+            continue;
         }
+
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(name) {
+                found = true;
+            }
+            skip_meta_value(&meta)
+        });
     }
-    false
+    found
+}
+
+/// Consumes the value of a nested meta item we don't care about,
+/// e.g. `default = "..."` or `with(...)`, so the following items still parse.
+fn skip_meta_value(meta: &ParseNestedMeta) -> Result<()> {
+    meta.input.step(|cursor| {
+        let mut rest = *cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            if matches!(&tt, TokenTree::Punct(p) if p.as_char() == ',') {
+                break;
+            }
+            rest = next;
+        }
+        Ok(((), rest))
+    })
+}
+
+/// Converts a `rename_all` value, rejecting rules serde doesn't know.
+fn rename_rule(value: &LitStr) -> Result<RenameRule<'static>> {
+    string_to_rename_rule(&value.value()).ok_or_else(|| {
+        syn::Error::new_spanned(
+            value,
+            "unknown rename rule, expected one of: lowercase, UPPERCASE, \
+             PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, \
+             kebab-case, SCREAMING-KEBAB-CASE",
+        )
+    })
 }
 
 fn string_to_rename_rule(s: &str) -> Option<RenameRule<'static>> {
@@ -0,0 +1,41 @@
+use core::any::Any;
+
+/// Runtime access to field values by name.
+///
+/// Implemented by the derive macro for structs without lifetime parameters;
+/// names are the original field names in [`FIELDS`](crate::VisitFields::FIELDS),
+/// including fields spliced in by `#[serde(flatten)]`. Fields excluded from
+/// `FIELDS` are not accessible.
+///
+/// ```rust
+/// use field_kinds::{FieldAccess, FieldKinds};
+///
+/// #[derive(FieldKinds)]
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let mut user = User { id: 7, name: "Ann".into() };
+/// assert_eq!(user.get_as::<u64>("id"), Some(&7));
+///
+/// if let Some(name) = user.get_mut("name").and_then(|v| v.downcast_mut::<String>()) {
+///     name.push_str("ie");
+/// }
+/// assert_eq!(user.name, "Annie");
+/// ```
+pub trait FieldAccess {
+    /// Returns a reference to the value of the field `name`.
+    fn get(&self, name: &str) -> Option<&dyn Any>;
+
+    /// Returns a mutable reference to the value of the field `name`.
+    fn get_mut(&mut self, name: &str) -> Option<&mut dyn Any>;
+
+    /// Returns the value of the field `name` if it has type `T`.
+    fn get_as<T: Any>(&self, name: &str) -> Option<&T>
+    where
+        Self: Sized,
+    {
+        self.get(name)?.downcast_ref()
+    }
+}
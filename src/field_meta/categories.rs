@@ -1,10 +1,17 @@
 use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::num::{
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
     NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    Saturating, Wrapping,
 };
-use std::sync::Arc;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::field_meta::visitors::Schema;
 
 /// A type-safe wrapper for field category names.
 ///
@@ -75,6 +82,8 @@ pub trait TypeCategory: 'static + Copy {
 }
 
 /// Marker type for numeric types (`i8`-`i128`, `u8`-`u128`, `f32`, `f64`, `isize`, `usize`).
+///
+/// See [`NumericClass`] for telling integers, floats and decimals apart.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Numeric;
 
@@ -94,6 +103,26 @@ pub struct Optional;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Collection;
 
+/// Marker type for date, time and duration types (`SystemTime`, `Instant`,
+/// `Duration`, and `chrono`/`time` types behind their cargo features).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Temporal;
+
+/// Marker type for identifiers (`uuid::Uuid` behind the `uuid` feature, or
+/// any field marked `#[field_kinds(identifier)]`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Identifier;
+
+/// Marker type for binary data (`Box<[u8]>`, `bytes::Bytes` behind the `bytes`
+/// feature, or any field marked `#[field_kinds(binary)]`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Binary;
+
+/// Marker type for structs deriving `FieldKinds`; their fields are
+/// reachable through [`FieldMeta::schema`](crate::FieldMeta::schema).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record;
+
 /// Marker type for types that don't match any known category.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Unknown;
@@ -113,6 +142,18 @@ impl TypeCategory for Optional {
 impl TypeCategory for Collection {
     const NAME: &'static str = "collection";
 }
+impl TypeCategory for Temporal {
+    const NAME: &'static str = "temporal";
+}
+impl TypeCategory for Identifier {
+    const NAME: &'static str = "identifier";
+}
+impl TypeCategory for Binary {
+    const NAME: &'static str = "binary";
+}
+impl TypeCategory for Record {
+    const NAME: &'static str = "record";
+}
 impl TypeCategory for Unknown {
     const NAME: &'static str = "unknown";
 }
@@ -130,10 +171,158 @@ impl Category {
     pub const OPTIONAL: Self = <Optional as TypeCategory>::CATEGORY;
     /// Collection types (`Vec`, `HashSet`, `HashMap`, arrays, slices).
     pub const COLLECTION: Self = <Collection as TypeCategory>::CATEGORY;
+    /// Date, time and duration types (`SystemTime`, `Duration`, `chrono`, `time`).
+    pub const TEMPORAL: Self = <Temporal as TypeCategory>::CATEGORY;
+    /// Identifiers (`uuid::Uuid`, fields marked `#[field_kinds(identifier)]`).
+    pub const IDENTIFIER: Self = <Identifier as TypeCategory>::CATEGORY;
+    /// Binary data (`Box<[u8]>`, `bytes::Bytes`, fields marked `#[field_kinds(binary)]`).
+    pub const BINARY: Self = <Binary as TypeCategory>::CATEGORY;
+    /// Structs deriving `FieldKinds`.
+    pub const RECORD: Self = <Record as TypeCategory>::CATEGORY;
     /// Types that don't match any known category.
     pub const UNKNOWN: Self = <Unknown as TypeCategory>::CATEGORY;
 }
 
+/// Kind of number behind a [`Numeric`] type, for picking e.g. a column type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumericClass {
+    /// Integers: `i8`-`i128`, `u8`-`u128`, `isize`, `usize`, `NonZero*`.
+    Integer,
+    /// Binary floating point: `f32`, `f64`.
+    Float,
+    /// Exact decimals: `rust_decimal::Decimal`, `bigdecimal::BigDecimal`.
+    Decimal,
+}
+
+/// Machine representation of a primitive numeric type.
+///
+/// # Example
+///
+/// ```rust
+/// use field_kinds::{Categorized, NumericKind};
+///
+/// assert_eq!(<u16 as Categorized>::NUMERIC_KIND, Some(NumericKind::unsigned(16)));
+/// assert!(<f64 as Categorized>::NUMERIC_KIND.is_some_and(|k| k.float));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericKind {
+    /// Whether negative values are representable (always `true` for floats).
+    pub signed: bool,
+    /// Width in bits.
+    pub bits: u32,
+    /// Whether this is a floating point type.
+    pub float: bool,
+}
+
+impl NumericKind {
+    /// A signed integer of the given width.
+    pub const fn signed(bits: u32) -> Self {
+        Self {
+            signed: true,
+            bits,
+            float: false,
+        }
+    }
+
+    /// An unsigned integer of the given width.
+    pub const fn unsigned(bits: u32) -> Self {
+        Self {
+            signed: false,
+            bits,
+            float: false,
+        }
+    }
+
+    /// A floating point number of the given width.
+    pub const fn float(bits: u32) -> Self {
+        Self {
+            signed: true,
+            bits,
+            float: true,
+        }
+    }
+}
+
+/// A type's category together with the categories of its element types.
+///
+/// Forms a chain for nested containers: a field of type
+/// `Option<Vec<String>>` is `optional` → `collection` → `text`.
+///
+/// # Example
+///
+/// ```rust
+/// use field_kinds::{Category, FieldKinds, FieldKindsExt};
+/// use std::collections::HashMap;
+///
+/// #[derive(FieldKinds)]
+/// struct Profile {
+///     aliases: Option<Vec<String>>,
+///     scores: HashMap<String, u32>,
+/// }
+///
+/// let aliases = Profile::find_by_name("aliases").unwrap();
+/// let chain: Vec<_> = aliases.category_chain().collect();
+/// assert_eq!(chain, [Category::OPTIONAL, Category::COLLECTION, Category::TEXT]);
+///
+/// let scores = Profile::find_by_name("scores").unwrap();
+/// assert_eq!(scores.key.unwrap().category, Category::TEXT);
+/// assert_eq!(scores.inner.unwrap().category, Category::NUMERIC);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NestedCategory {
+    /// Category of the type itself.
+    pub category: Category,
+    /// Element category: `T` for `Option<T>`, `Vec<T>`, sets and arrays,
+    /// the value type for maps.
+    pub inner: Option<&'static Self>,
+    /// Key category for maps.
+    pub key: Option<&'static Self>,
+    /// Kind of number for numeric types.
+    pub numeric_class: Option<NumericClass>,
+    /// Machine representation for primitive numeric types.
+    pub numeric_kind: Option<NumericKind>,
+    /// Field metadata for types deriving `FieldKinds`.
+    pub schema: Option<Schema>,
+}
+
+impl NestedCategory {
+    /// Builds the category node for `T`.
+    ///
+    /// Element categories come from [`Categorized::INNER`] and
+    /// [`Categorized::KEY`], which the standard containers leave unset.
+    pub const fn of<T: Categorized + ?Sized>() -> Self {
+        Self {
+            category: <T::Category as TypeCategory>::CATEGORY,
+            inner: T::INNER,
+            key: T::KEY,
+            numeric_class: T::NUMERIC_CLASS,
+            numeric_kind: T::NUMERIC_KIND,
+            schema: T::SCHEMA,
+        }
+    }
+
+    /// Replaces the element and key categories.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_nested(
+        mut self,
+        inner: Option<&'static Self>,
+        key: Option<&'static Self>,
+    ) -> Self {
+        self.inner = inner;
+        self.key = key;
+        self
+    }
+
+    /// Iterates over this category and its inner categories, outermost first.
+    pub fn chain(&self) -> impl Iterator<Item = Category> {
+        core::iter::successors(Some(self), |node| node.inner)
+            .map(|node| node.category)
+    }
+}
+
 /// Trait for mapping Rust types to their categories.
 ///
 /// Implement this trait for custom types to enable automatic categorization.
@@ -152,88 +341,69 @@ impl Category {
 pub trait Categorized {
     /// The category marker type for this type.
     type Category: TypeCategory;
-}
 
-impl Categorized for u8 {
-    type Category = Numeric;
-}
-impl Categorized for u16 {
-    type Category = Numeric;
-}
-impl Categorized for u32 {
-    type Category = Numeric;
-}
-impl Categorized for u64 {
-    type Category = Numeric;
-}
-impl Categorized for u128 {
-    type Category = Numeric;
-}
-impl Categorized for usize {
-    type Category = Numeric;
-}
+    /// Category of the element type for containers, `None` otherwise.
+    ///
+    /// The standard containers (`Option<T>`, `Vec<T>`, maps, ...) leave this
+    /// `None` so that their elements need not implement `Categorized`; the
+    /// derive macro reads element categories from the field type instead.
+    const INNER: Option<&'static NestedCategory> = None;
 
-impl Categorized for i8 {
-    type Category = Numeric;
-}
-impl Categorized for i16 {
-    type Category = Numeric;
-}
-impl Categorized for i32 {
-    type Category = Numeric;
-}
-impl Categorized for i64 {
-    type Category = Numeric;
-}
-impl Categorized for i128 {
-    type Category = Numeric;
-}
-impl Categorized for isize {
-    type Category = Numeric;
-}
+    /// Category of the key type for maps, `None` otherwise.
+    const KEY: Option<&'static NestedCategory> = None;
 
-impl Categorized for f32 {
-    type Category = Numeric;
-}
-impl Categorized for f64 {
-    type Category = Numeric;
-}
+    /// Kind of number for [`Numeric`] types, `None` otherwise.
+    const NUMERIC_CLASS: Option<NumericClass> = None;
 
-impl Categorized for NonZeroU8 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroU16 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroU32 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroU64 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroU128 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroUsize {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroI8 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroI16 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroI32 {
-    type Category = Numeric;
-}
-impl Categorized for NonZeroI64 {
-    type Category = Numeric;
+    /// Signedness and width for primitive numeric types, `None` otherwise
+    /// (including decimals).
+    const NUMERIC_KIND: Option<NumericKind> = None;
+
+    /// Field metadata for types deriving `FieldKinds`, `None` otherwise.
+    const SCHEMA: Option<Schema> = None;
 }
-impl Categorized for NonZeroI128 {
-    type Category = Numeric;
+
+/// Implements `Categorized` as [`Numeric`] with the given class and kind.
+macro_rules! impl_numeric {
+    ($($ty:ty => $class:ident, $kind:expr;)*) => {
+        $(
+            impl Categorized for $ty {
+                type Category = Numeric;
+                const NUMERIC_CLASS: Option<NumericClass> =
+                    Some(NumericClass::$class);
+                const NUMERIC_KIND: Option<NumericKind> = Some($kind);
+            }
+        )*
+    };
 }
-impl Categorized for NonZeroIsize {
-    type Category = Numeric;
+
+impl_numeric! {
+    u8 => Integer, NumericKind::unsigned(u8::BITS);
+    u16 => Integer, NumericKind::unsigned(u16::BITS);
+    u32 => Integer, NumericKind::unsigned(u32::BITS);
+    u64 => Integer, NumericKind::unsigned(u64::BITS);
+    u128 => Integer, NumericKind::unsigned(u128::BITS);
+    usize => Integer, NumericKind::unsigned(usize::BITS);
+    i8 => Integer, NumericKind::signed(i8::BITS);
+    i16 => Integer, NumericKind::signed(i16::BITS);
+    i32 => Integer, NumericKind::signed(i32::BITS);
+    i64 => Integer, NumericKind::signed(i64::BITS);
+    i128 => Integer, NumericKind::signed(i128::BITS);
+    isize => Integer, NumericKind::signed(isize::BITS);
+    f32 => Float, NumericKind::float(32);
+    f64 => Float, NumericKind::float(64);
+    NonZeroU8 => Integer, NumericKind::unsigned(NonZeroU8::BITS);
+    NonZeroU16 => Integer, NumericKind::unsigned(NonZeroU16::BITS);
+    NonZeroU32 => Integer, NumericKind::unsigned(NonZeroU32::BITS);
+    NonZeroU64 => Integer, NumericKind::unsigned(NonZeroU64::BITS);
+    NonZeroU128 => Integer, NumericKind::unsigned(NonZeroU128::BITS);
+    NonZeroUsize => Integer, NumericKind::unsigned(NonZeroUsize::BITS);
+    NonZeroI8 => Integer, NumericKind::signed(NonZeroI8::BITS);
+    NonZeroI16 => Integer, NumericKind::signed(NonZeroI16::BITS);
+    NonZeroI32 => Integer, NumericKind::signed(NonZeroI32::BITS);
+    NonZeroI64 => Integer, NumericKind::signed(NonZeroI64::BITS);
+    NonZeroI128 => Integer, NumericKind::signed(NonZeroI128::BITS);
+    NonZeroIsize => Integer, NumericKind::signed(NonZeroIsize::BITS);
 }
 
 impl Categorized for String {
@@ -254,7 +424,7 @@ impl Categorized for Cow<'_, str> {
 impl Categorized for Arc<str> {
     type Category = Text;
 }
-impl Categorized for std::rc::Rc<str> {
+impl Categorized for Rc<str> {
     type Category = Text;
 }
 
@@ -262,6 +432,24 @@ impl Categorized for bool {
     type Category = Bool;
 }
 
+impl Categorized for SystemTime {
+    type Category = Temporal;
+}
+impl Categorized for Instant {
+    type Category = Temporal;
+}
+impl Categorized for Duration {
+    type Category = Temporal;
+}
+
+// `Vec<u8>` and `[u8; N]` stay collections: a specialized impl would overlap
+// the blanket ones. Use `#[field_kinds(binary)]` for those.
+impl Categorized for Box<[u8]> {
+    type Category = Binary;
+}
+
+// Element and key types need not be `Categorized`: the derive macro reads
+// their categories from the field type, see `FieldMeta.inner`.
 impl<T> Categorized for Option<T> {
     type Category = Optional;
 }
@@ -287,3 +475,36 @@ impl<K, V, S> Categorized for HashMap<K, V, S> {
 impl<K, V> Categorized for BTreeMap<K, V> {
     type Category = Collection;
 }
+
+/// Implements `Categorized` for wrappers that take the category of the
+/// type they wrap.
+macro_rules! forward_categorized {
+    ($($wrapper:ident),* $(,)?) => {
+        $(
+            impl<T: Categorized> Categorized for $wrapper<T> {
+                type Category = T::Category;
+                const INNER: Option<&'static NestedCategory> = T::INNER;
+                const KEY: Option<&'static NestedCategory> = T::KEY;
+                const NUMERIC_CLASS: Option<NumericClass> = T::NUMERIC_CLASS;
+                const NUMERIC_KIND: Option<NumericKind> = T::NUMERIC_KIND;
+                const SCHEMA: Option<Schema> = T::SCHEMA;
+            }
+        )*
+    };
+}
+
+forward_categorized!(
+    Box, Rc, Arc, Cell, RefCell, Mutex, RwLock, Wrapping, Saturating, Reverse,
+);
+
+impl<B: Categorized + Clone> Categorized for Cow<'_, B> {
+    type Category = B::Category;
+    const INNER: Option<&'static NestedCategory> = B::INNER;
+    const KEY: Option<&'static NestedCategory> = B::KEY;
+    const NUMERIC_CLASS: Option<NumericClass> = B::NUMERIC_CLASS;
+    const NUMERIC_KIND: Option<NumericKind> = B::NUMERIC_KIND;
+    const SCHEMA: Option<Schema> = B::SCHEMA;
+}
+impl<T: Clone> Categorized for Cow<'_, [T]> {
+    type Category = Collection;
+}
@@ -0,0 +1,84 @@
+use core::fmt::Debug;
+
+use crate::field_meta::visitors::{FieldMeta, VisitFields};
+
+/// Placeholder for the values of fields tagged `"sensitive"`.
+pub const REDACTED: &str = "<redacted>";
+
+/// A field whose value differs between two instances, see [`FieldDiff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FieldChange {
+    /// Metadata of the changed field.
+    pub meta: &'static FieldMeta,
+    /// `Debug` rendering of the old value, or [`REDACTED`].
+    pub before: String,
+    /// `Debug` rendering of the new value, or [`REDACTED`].
+    pub after: String,
+}
+
+impl FieldChange {
+    /// Renders `before` and `after`, redacting fields tagged `"sensitive"`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn new(
+        meta: &'static FieldMeta,
+        before: &dyn Debug,
+        after: &dyn Debug,
+    ) -> Self {
+        if meta.has_tag("sensitive") {
+            return Self {
+                meta,
+                before: REDACTED.to_owned(),
+                after: REDACTED.to_owned(),
+            };
+        }
+        Self {
+            meta,
+            before: format!("{before:?}"),
+            after: format!("{after:?}"),
+        }
+    }
+
+    /// Returns `true` if the values were replaced by [`REDACTED`].
+    #[must_use]
+    pub const fn is_redacted(&self) -> bool {
+        self.meta.has_tag("sensitive")
+    }
+}
+
+/// Field-by-field comparison of two values.
+///
+/// Implemented by the derive macro for structs marked
+/// `#[field_kinds(diff)]`; every field must implement `PartialEq` and
+/// `Debug`, and flattened fields `FieldDiff`. Only fields in
+/// [`FIELDS`](VisitFields::FIELDS) are compared, so `#[field_kinds(skip)]`
+/// fields never show up. Fields tagged `#[field_tags("sensitive")]` are
+/// reported with both values replaced by [`REDACTED`].
+///
+/// ```rust
+/// use field_kinds::{FieldDiff, FieldKinds};
+///
+/// #[derive(FieldKinds)]
+/// #[field_kinds(diff)]
+/// struct Account {
+///     email: String,
+///     #[field_tags("sensitive")]
+///     password_hash: String,
+///     logins: u32,
+/// }
+///
+/// let old = Account { email: "a@x.io".into(), password_hash: "1f2e".into(), logins: 3 };
+/// let new = Account { email: "b@x.io".into(), password_hash: "9c8d".into(), logins: 3 };
+///
+/// let changes = FieldDiff::diff(&old, &new);
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0].meta.name, "email");
+/// assert_eq!(changes[0].before, r#""a@x.io""#);
+/// assert_eq!(changes[1].after, "<redacted>");
+/// ```
+pub trait FieldDiff: VisitFields {
+    /// Returns the fields whose values differ between `self` and `new`, in
+    /// [`FIELDS`](VisitFields::FIELDS) order.
+    fn diff(&self, new: &Self) -> Vec<FieldChange>;
+}
@@ -0,0 +1,354 @@
+use core::fmt;
+
+use crate::field_meta::categories::Category;
+use crate::field_meta::set_from_str::{FieldSetError, SetFieldFromStr};
+use crate::field_meta::visitors::{FieldMeta, VisitFields};
+
+/// A single environment variable that could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnvVarError {
+    /// The variable for a required field is not set.
+    Missing {
+        /// Variable name, e.g. `APP_POOL_SIZE`.
+        var: String,
+    },
+    /// The variable is set but its value was rejected.
+    Invalid {
+        /// Variable name, e.g. `APP_POOL_SIZE`.
+        var: String,
+        /// Why the value was rejected.
+        error: FieldSetError,
+    },
+}
+
+impl fmt::Display for EnvVarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { var } => write!(f, "{var} is not set"),
+            Self::Invalid { var, error } => write!(f, "{var}: {error}"),
+        }
+    }
+}
+
+/// Every variable [`FromEnv`] failed to apply, in field order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvError {
+    /// The individual failures; never empty.
+    pub errors: Vec<EnvVarError>,
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid environment: ")?;
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for EnvError {}
+
+/// Loads struct fields from environment variables.
+///
+/// Implemented by the derive macro for structs marked
+/// `#[field_kinds(env)]`. Each field in [`FIELDS`](VisitFields::FIELDS) reads
+/// `PREFIX_NAME`, where `NAME` is the serialized name in
+/// `SCREAMING_SNAKE_CASE` (see [`env_var_name`]). Values are parsed
+/// according to the field's category:
+///
+/// - most fields like [`SetFieldFromStr`]; bools also accept `1`/`0`,
+///   `yes`/`no` and `on`/`off`
+/// - lists and sets (`Vec<T>`, `HashSet<T>`, ...) as comma-separated
+///   values; they may be unset, like `Option` fields
+/// - nested records are not read themselves: their fields are, from
+///   `PREFIX_NAME_FIELD` (`APP_DB_HOST` for `db: Db`). The nested type
+///   must be marked `#[field_kinds(env)]` too
+///
+/// Fields serde doesn't deserialize (`#[serde(skip)]` or
+/// `#[serde(skip_deserializing)]`) are never read and keep their value.
+/// Fields whose type has no `FromStr` (e.g. `Duration`) keep their value
+/// when unset; setting their variable is reported as
+/// [`FieldSetError::Unsupported`].
+///
+/// ```rust
+/// use field_kinds::{FieldKinds, FromEnv};
+///
+/// #[derive(FieldKinds, Default)]
+/// #[field_kinds(env)]
+/// struct DbConfig {
+///     pool_size: u32,
+///     verbose: bool,
+///     replica: Option<String>,
+///     hosts: Vec<String>,
+/// }
+///
+/// let vars = |name: &str| match name {
+///     "DB_POOL_SIZE" => Some("20".to_owned()),
+///     "DB_VERBOSE" => Some("yes".to_owned()),
+///     "DB_HOSTS" => Some("a.internal, b.internal".to_owned()),
+///     _ => None,
+/// };
+/// let config = DbConfig::from_env_with("DB", vars).unwrap();
+/// assert_eq!(config.pool_size, 20);
+/// assert!(config.verbose);
+/// assert_eq!(config.replica, None);
+/// assert_eq!(config.hosts, ["a.internal", "b.internal"]);
+/// ```
+pub trait FromEnv: VisitFields + SetFieldFromStr {
+    /// Builds `Self` from `Default`, then applies every variable.
+    ///
+    /// # Errors
+    ///
+    /// Reports every invalid variable, and a missing one for every field
+    /// that is not optional.
+    fn from_env(prefix: &str) -> Result<Self, EnvError>
+    where
+        Self: Default,
+    {
+        Self::from_env_with(prefix, read_var)
+    }
+
+    /// Like [`from_env`](Self::from_env), reading variables from `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// See [`from_env`](Self::from_env).
+    fn from_env_with(
+        prefix: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, EnvError>
+    where
+        Self: Default,
+    {
+        let mut value = Self::default();
+        load(&mut value, prefix, &lookup, true)?;
+        Ok(value)
+    }
+
+    /// Overrides fields whose variable is set; unset variables are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Reports every invalid variable. Valid ones are applied regardless.
+    fn apply_env(&mut self, prefix: &str) -> Result<(), EnvError> {
+        self.apply_env_with(prefix, read_var)
+    }
+
+    /// Like [`apply_env`](Self::apply_env), reading variables from `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// See [`apply_env`](Self::apply_env).
+    fn apply_env_with(
+        &mut self,
+        prefix: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), EnvError> {
+        load(self, prefix, &lookup, false)
+    }
+
+    /// Sets the field at `path` (Rust names, `db.host` for nested records)
+    /// from a variable's value. Overridden by the derive macro to parse
+    /// lists and reach into nested records.
+    ///
+    /// # Errors
+    ///
+    /// As [`SetFieldFromStr::set_field_from_str`].
+    #[doc(hidden)]
+    fn set_env_var(
+        &mut self,
+        path: &str,
+        value: &str,
+    ) -> Result<(), FieldSetError> {
+        self.set_field_from_str(path, value)
+    }
+}
+
+/// Probes whether a nested or flattened field implements [`FromEnv`]: the
+/// inherent `set_env_var` delegates to it, otherwise [`EnvFallback`] is
+/// used.
+pub struct EnvProbe<'a, T>(pub &'a mut T);
+
+impl<T: FromEnv> EnvProbe<'_, T> {
+    /// Delegates to [`FromEnv::set_env_var`].
+    ///
+    /// # Errors
+    ///
+    /// As [`FromEnv::set_env_var`].
+    pub fn set_env_var(
+        self,
+        path: &str,
+        value: &str,
+    ) -> Result<(), FieldSetError> {
+        self.0.set_env_var(path, value)
+    }
+}
+
+/// Used when the probed type does not implement [`FromEnv`].
+pub trait EnvFallback {
+    /// Always fails with [`FieldSetError::UnknownField`].
+    ///
+    /// # Errors
+    ///
+    /// Always.
+    fn set_env_var(self, path: &str, _value: &str) -> Result<(), FieldSetError>
+    where
+        Self: Sized,
+    {
+        Err(FieldSetError::UnknownField {
+            name: path.to_owned(),
+        })
+    }
+}
+
+impl<T> EnvFallback for EnvProbe<'_, T> {}
+
+/// Returns the variable name for `field`: `prefix` and the serialized name
+/// in `SCREAMING_SNAKE_CASE`, joined by `_`. An empty prefix is omitted.
+///
+/// ```rust
+/// use field_kinds::{FieldKinds, FieldKindsExt, env_var_name};
+///
+/// #[derive(FieldKinds)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Config {
+///     pool_size: u32,
+/// }
+///
+/// let field = Config::find_by_name("pool_size").unwrap();
+/// assert_eq!(env_var_name("APP", field), "APP_POOL_SIZE");
+/// assert_eq!(env_var_name("", field), "POOL_SIZE");
+/// ```
+pub fn env_var_name(prefix: &str, field: &FieldMeta) -> String {
+    let mut var =
+        String::with_capacity(prefix.len() + field.serialized_name.len() + 4);
+    if !prefix.is_empty() {
+        var.push_str(prefix.trim_end_matches('_'));
+        var.push('_');
+    }
+    let mut prev: Option<char> = None;
+    for c in field.serialized_name.chars() {
+        if c == '-' || c == ' ' || c == '.' {
+            var.push('_');
+        } else {
+            if c.is_uppercase()
+                && prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit())
+            {
+                var.push('_');
+            }
+            var.extend(c.to_uppercase());
+        }
+        prev = Some(c);
+    }
+    var
+}
+
+fn read_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+fn load<T: FromEnv + ?Sized>(
+    target: &mut T,
+    prefix: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+    require: bool,
+) -> Result<(), EnvError> {
+    let mut errors = Vec::new();
+    load_fields(
+        target,
+        T::FIELDS,
+        &Source {
+            prefix,
+            path: "",
+            lookup,
+            require,
+        },
+        &mut errors,
+    );
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(EnvError { errors })
+    }
+}
+
+/// Where [`load_fields`] reads from: variables under `prefix`, set at
+/// paths under `path` (empty or ending in `.`).
+struct Source<'a, F> {
+    prefix: &'a str,
+    path: &'a str,
+    lookup: &'a F,
+    require: bool,
+}
+
+fn load_fields<T: FromEnv + ?Sized, F: Fn(&str) -> Option<String>>(
+    target: &mut T,
+    fields: &'static [FieldMeta],
+    source: &Source<'_, F>,
+    errors: &mut Vec<EnvVarError>,
+) {
+    for field in fields {
+        if !field.is_deserialized() {
+            continue;
+        }
+        let var = env_var_name(source.prefix, field);
+        let path = format!("{}{}", source.path, field.name);
+        if field.category == Category::RECORD
+            && let Some(nested) = field.record_fields()
+        {
+            let nested_path = format!("{path}.");
+            let nested_source = Source {
+                prefix: &var,
+                path: &nested_path,
+                ..*source
+            };
+            load_fields(target, nested, &nested_source, errors);
+            continue;
+        }
+        match (source.lookup)(&var) {
+            Some(value) => {
+                let value = if is_bool(field) {
+                    bool_value(&value)
+                } else {
+                    &value
+                };
+                if let Err(error) = target.set_env_var(&path, value) {
+                    errors.push(EnvVarError::Invalid { var, error });
+                }
+            }
+            None if source.require && is_required(field) => {
+                errors.push(EnvVarError::Missing { var });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Whether an unset variable is an error: `Option`s and collections
+/// (lists, sets and maps) may be left unset, and so may fields that can't
+/// be set from a string at all.
+fn is_required(field: &FieldMeta) -> bool {
+    field.settable_from_str
+        && field.category != Category::OPTIONAL
+        && field.category != Category::COLLECTION
+}
+
+/// `bool` or `Option<bool>`.
+fn is_bool(field: &FieldMeta) -> bool {
+    field.category == Category::BOOL
+        || (field.category == Category::OPTIONAL
+            && field.inner_category() == Some(Category::BOOL))
+}
+
+fn bool_value(value: &str) -> &str {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "yes" | "on" => "true",
+        "0" | "no" | "off" => "false",
+        _ => value,
+    }
+}
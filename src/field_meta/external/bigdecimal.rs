@@ -0,0 +1,8 @@
+use bigdecimal::BigDecimal;
+
+use crate::field_meta::categories::{Categorized, Numeric, NumericClass};
+
+impl Categorized for BigDecimal {
+    type Category = Numeric;
+    const NUMERIC_CLASS: Option<NumericClass> = Some(NumericClass::Decimal);
+}
@@ -0,0 +1,10 @@
+use bytes::{Bytes, BytesMut};
+
+use crate::field_meta::categories::{Binary, Categorized};
+
+impl Categorized for Bytes {
+    type Category = Binary;
+}
+impl Categorized for BytesMut {
+    type Category = Binary;
+}
@@ -0,0 +1,21 @@
+use chrono::{
+    DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone,
+};
+
+use crate::field_meta::categories::{Categorized, Temporal};
+
+impl<Tz: TimeZone> Categorized for DateTime<Tz> {
+    type Category = Temporal;
+}
+impl Categorized for NaiveDateTime {
+    type Category = Temporal;
+}
+impl Categorized for NaiveDate {
+    type Category = Temporal;
+}
+impl Categorized for NaiveTime {
+    type Category = Temporal;
+}
+impl Categorized for TimeDelta {
+    type Category = Temporal;
+}
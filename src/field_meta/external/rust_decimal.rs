@@ -0,0 +1,8 @@
+use rust_decimal::Decimal;
+
+use crate::field_meta::categories::{Categorized, Numeric, NumericClass};
+
+impl Categorized for Decimal {
+    type Category = Numeric;
+    const NUMERIC_CLASS: Option<NumericClass> = Some(NumericClass::Decimal);
+}
@@ -0,0 +1,24 @@
+use time::{
+    Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime,
+};
+
+use crate::field_meta::categories::{Categorized, Temporal};
+
+impl Categorized for OffsetDateTime {
+    type Category = Temporal;
+}
+impl Categorized for PrimitiveDateTime {
+    type Category = Temporal;
+}
+impl Categorized for UtcDateTime {
+    type Category = Temporal;
+}
+impl Categorized for Date {
+    type Category = Temporal;
+}
+impl Categorized for Time {
+    type Category = Temporal;
+}
+impl Categorized for Duration {
+    type Category = Temporal;
+}
@@ -0,0 +1,10 @@
+use uuid::{NonNilUuid, Uuid};
+
+use crate::field_meta::categories::{Categorized, Identifier};
+
+impl Categorized for Uuid {
+    type Category = Identifier;
+}
+impl Categorized for NonNilUuid {
+    type Category = Identifier;
+}
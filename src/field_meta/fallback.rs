@@ -0,0 +1,68 @@
+//! Const probe used by the derive macro for `#[field_kinds(unknown_fallback)]`
+//! and for the element types of containers.
+//!
+//! `Probe::<T>::CATEGORY` resolves to the inherent constant when
+//! `T: Categorized` and to the [`Fallback`] trait constant otherwise, since
+//! inherent associated items take precedence but only apply when their
+//! bounds hold. This only works for concrete types and for generic
+//! parameters bounded by `Categorized`.
+
+use core::marker::PhantomData;
+
+use crate::field_meta::categories::{
+    Categorized, Category, NestedCategory, NumericClass, NumericKind,
+    TypeCategory,
+};
+use crate::field_meta::visitors::Schema;
+
+/// Zero-sized probe for the category of `T`.
+pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+impl<T: Categorized + ?Sized> Probe<T> {
+    /// Category of `T`.
+    pub const CATEGORY: Category = <T::Category as TypeCategory>::CATEGORY;
+    /// Element category of `T`.
+    pub const INNER: Option<&'static NestedCategory> = T::INNER;
+    /// Key category of `T`.
+    pub const KEY: Option<&'static NestedCategory> = T::KEY;
+    /// Numeric class of `T`.
+    pub const NUMERIC_CLASS: Option<NumericClass> = T::NUMERIC_CLASS;
+    /// Numeric kind of `T`.
+    pub const NUMERIC_KIND: Option<NumericKind> = T::NUMERIC_KIND;
+    /// Field metadata of `T`.
+    pub const SCHEMA: Option<Schema> = T::SCHEMA;
+    /// Category node of `T`.
+    pub const NODE: NestedCategory = NestedCategory::of::<T>();
+    /// Category node of `T`, for element types naming generic parameters.
+    pub const NESTED: Option<&'static NestedCategory> =
+        Some(&NestedCategory::of::<T>());
+}
+
+/// Constants used when `T` does not implement `Categorized`.
+pub trait Fallback {
+    /// Always [`Category::UNKNOWN`].
+    const CATEGORY: Category = Category::UNKNOWN;
+    /// Always `None`.
+    const INNER: Option<&'static NestedCategory> = None;
+    /// Always `None`.
+    const KEY: Option<&'static NestedCategory> = None;
+    /// Always `None`.
+    const NUMERIC_CLASS: Option<NumericClass> = None;
+    /// Always `None`.
+    const NUMERIC_KIND: Option<NumericKind> = None;
+    /// Always `None`.
+    const SCHEMA: Option<Schema> = None;
+    /// A node with only [`Category::UNKNOWN`].
+    const NODE: NestedCategory = NestedCategory {
+        category: Category::UNKNOWN,
+        inner: None,
+        key: None,
+        numeric_class: None,
+        numeric_kind: None,
+        schema: None,
+    };
+    /// Always `None`: a generic parameter may still be `Categorized`.
+    const NESTED: Option<&'static NestedCategory> = None;
+}
+
+impl<T: ?Sized> Fallback for Probe<T> {}
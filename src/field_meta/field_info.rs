@@ -1,4 +1,5 @@
 use crate::field_meta::categories::TypeCategory;
+use crate::field_meta::tags::TagValue;
 
 /// Trait representing compile-time information about a struct field.
 ///
@@ -9,10 +10,14 @@ pub trait FieldInfo {
     const NAME: &'static str;
     /// Serialized name (may differ due to rename attributes).
     const SERIALIZED_NAME: &'static str;
+    /// Name used when deserializing (defaults to [`Self::SERIALIZED_NAME`]).
+    const DESERIALIZE_NAME: &'static str = Self::SERIALIZED_NAME;
     /// Category name as string (e.g., "numeric", "text").
     const CATEGORY_NAME: &'static str;
     /// Custom tags added via `#[field_tags(...)]`.
     const TAGS: &'static [&'static str];
+    /// Key/value tags added via `#[field_tags(key = value)]`.
+    const TAG_VALUES: &'static [(&'static str, TagValue)] = &[];
 
     /// The Rust type of the field.
     type Value;
@@ -0,0 +1,50 @@
+//! Const helpers used by the derive macro to splice `#[serde(flatten)]`
+//! fields into `FIELDS`.
+
+use crate::field_meta::categories::Category;
+use crate::field_meta::visitors::FieldMeta;
+
+/// A run of field metadata, tagged with the flattened field it came from.
+pub type FieldsPart = (&'static [FieldMeta], Option<&'static str>);
+
+/// Total number of entries across all parts.
+pub const fn flattened_len(parts: &[FieldsPart]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].0.len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenates all parts into a single array.
+///
+/// Entries of a flattened part are marked with the flattened field's name,
+/// replacing any marker set by a nested flatten.
+///
+/// # Panics
+///
+/// Panics (at compile time) if `N` doesn't match [`flattened_len`].
+pub const fn flatten_fields<const N: usize>(
+    parts: &[FieldsPart],
+) -> [FieldMeta; N] {
+    let mut out = [FieldMeta::new("", "", Category::UNKNOWN, &[]); N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let (fields, source) = parts[i];
+        let mut j = 0;
+        while j < fields.len() {
+            out[n] = match source {
+                Some(field) => fields[j].with_flattened_from(field),
+                None => fields[j],
+            };
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(n == N, "flattened field count mismatch");
+    out
+}
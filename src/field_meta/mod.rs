@@ -1,13 +1,39 @@
+mod access;
 mod categories;
+mod diff;
+mod env;
+mod external;
+mod fallback;
 mod field_info;
+mod flatten;
+mod paths;
+mod set_from_str;
+mod tags;
+mod values;
+mod variants;
 mod visitors;
 
+pub use access::FieldAccess;
 pub use categories::{
-    Bool, Categorized, Category, Collection, Numeric, Optional, Text,
-    TypeCategory, Unknown,
+    Binary, Bool, Categorized, Category, Collection, Identifier,
+    NestedCategory, Numeric, NumericClass, NumericKind, Optional, Record,
+    Temporal, Text, TypeCategory, Unknown,
 };
+pub use diff::{FieldChange, FieldDiff, REDACTED};
+pub use env::{
+    EnvError, EnvFallback, EnvProbe, EnvVarError, FromEnv, env_var_name,
+};
+pub use fallback::{Fallback, Probe};
 pub use field_info::FieldInfo;
-pub use visitors::{FieldMeta, VisitFields};
+pub use flatten::{flatten_fields, flattened_len};
+pub use paths::FieldPath;
+pub use set_from_str::{
+    FieldSetError, ParseFallback, ParseProbe, SetFieldFromStr,
+};
+pub use tags::TagValue;
+pub use values::{FieldValueVisitor, VisitValues};
+pub use variants::{VariantKind, VariantMeta, VisitVariants};
+pub use visitors::{FieldMeta, Schema, TypeMeta, VisitFields};
 
 /// Extension trait providing convenient methods for field introspection.
 ///
@@ -24,13 +50,34 @@ pub trait FieldKindsExt: VisitFields {
     }
 
     /// Returns serialized field names (respecting `#[serde(rename)]`).
+    ///
+    /// Fields skipped by serde are included unless the struct opts out with
+    /// `#[field_kinds(exclude_serde_skipped)]`, which leaves out every field
+    /// serde doesn't write; see [`FieldMeta::is_serialized`].
     fn serialized_names() -> Vec<&'static str> {
-        Self::FIELDS.iter().map(|f| f.serialized_name).collect()
+        Self::serialized_names_iter().collect()
     }
 
     /// Returns an iterator over serialized field names.
     fn serialized_names_iter() -> impl Iterator<Item = &'static str> {
-        Self::FIELDS.iter().map(|f| f.serialized_name)
+        let exclude = Self::TYPE.exclude_serde_skipped;
+        Self::FIELDS
+            .iter()
+            .filter(move |f| !exclude || f.is_serialized())
+            .map(|f| f.serialized_name)
+    }
+
+    /// Returns field names used when deserializing.
+    ///
+    /// With `#[field_kinds(exclude_serde_skipped)]`, fields serde doesn't
+    /// read are left out; see [`FieldMeta::is_deserialized`].
+    fn deserialize_names() -> Vec<&'static str> {
+        let exclude = Self::TYPE.exclude_serde_skipped;
+        Self::FIELDS
+            .iter()
+            .filter(|f| !exclude || f.is_deserialized())
+            .map(|f| f.deserialize_name)
+            .collect()
     }
 
     /// Returns field names matching the given category.
@@ -42,6 +89,59 @@ pub trait FieldKindsExt: VisitFields {
             .collect()
     }
 
+    /// Returns field names whose element type has the given category,
+    /// e.g. `Option<String>` or `Vec<String>` for [`Category::TEXT`].
+    ///
+    /// For maps the element type is the value type.
+    fn fields_by_inner_category(category: Category) -> Vec<&'static str> {
+        Self::FIELDS
+            .iter()
+            .filter(|f| f.inner_category() == Some(category))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Returns names of numeric fields of the given class, e.g. all
+    /// [`NumericClass::Decimal`] fields.
+    fn fields_by_numeric_class(class: NumericClass) -> Vec<&'static str> {
+        Self::FIELDS
+            .iter()
+            .filter(|f| f.numeric_class == Some(class))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Returns names of fields for which `predicate` holds.
+    ///
+    /// ```rust
+    /// use field_kinds::{FieldKinds, FieldKindsExt};
+    ///
+    /// #[derive(FieldKinds)]
+    /// struct Reading {
+    ///     id: u32,
+    ///     value: f64,
+    /// }
+    ///
+    /// let floats = Reading::fields_matching(|m| m.numeric_kind().is_some_and(|k| k.float));
+    /// assert_eq!(floats, vec!["value"]);
+    /// ```
+    fn fields_matching(
+        predicate: impl Fn(&FieldMeta) -> bool,
+    ) -> Vec<&'static str> {
+        Self::FIELDS
+            .iter()
+            .filter(|f| predicate(f))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Returns names of identifier fields: types categorized as
+    /// [`Category::IDENTIFIER`] (e.g. `uuid::Uuid`) and fields marked
+    /// `#[field_kinds(identifier)]`.
+    fn identifier_fields() -> Vec<&'static str> {
+        Self::fields_by_category(Category::IDENTIFIER)
+    }
+
     /// Returns field names that have the given tag.
     fn fields_by_tag(tag: &str) -> Vec<&'static str> {
         Self::FIELDS
@@ -51,6 +151,33 @@ pub trait FieldKindsExt: VisitFields {
             .collect()
     }
 
+    /// Returns names of fields whose key/value tag `key` equals `value`.
+    ///
+    /// ```rust
+    /// use field_kinds::{FieldKinds, FieldKindsExt};
+    ///
+    /// #[derive(FieldKinds)]
+    /// struct Request {
+    ///     #[field_tags(unit = "ms", max = 30_000)]
+    ///     timeout: u64,
+    ///     #[field_tags(unit = "bytes")]
+    ///     body_limit: u64,
+    /// }
+    ///
+    /// assert_eq!(Request::fields_with_tag_value("unit", "ms"), vec!["timeout"]);
+    /// assert_eq!(Request::fields_with_tag_value("max", 30_000), vec!["timeout"]);
+    /// ```
+    fn fields_with_tag_value<V>(key: &str, value: V) -> Vec<&'static str>
+    where
+        TagValue: PartialEq<V>,
+    {
+        Self::FIELDS
+            .iter()
+            .filter(|f| f.tag_value(key).is_some_and(|v| v == value))
+            .map(|f| f.name)
+            .collect()
+    }
+
     /// Returns an iterator over fields matching the given category.
     fn filter_by_category(
         category: Category,
@@ -80,6 +207,68 @@ pub trait FieldKindsExt: VisitFields {
         Self::FIELDS.iter().find(|f| f.serialized_name == name)
     }
 
+    /// Finds a field by the name used when deserializing.
+    fn find_by_deserialize_name(name: &str) -> Option<&'static FieldMeta> {
+        Self::FIELDS.iter().find(|f| f.deserialize_name == name)
+    }
+
+    /// Finds a field by any of its names: the Rust name, serialized name,
+    /// deserialize name or a `#[serde(alias)]`.
+    fn find_by_any_name(name: &str) -> Option<&'static FieldMeta> {
+        Self::FIELDS.iter().find(|f| f.has_name(name))
+    }
+
+    /// Finds a field by its declaration position.
+    ///
+    /// For tuple structs this is the position used to access the field
+    /// (`self.0`, `self.1`, ...). Entries spliced in by `#[serde(flatten)]`
+    /// are not matched, as their index is the position in the inner struct;
+    /// flattened maps and `Option`s are the struct's own fields and are.
+    fn find_by_index(index: usize) -> Option<&'static FieldMeta> {
+        Self::FIELDS
+            .iter()
+            .filter(|f| f.flattened_from.is_none_or(|from| from == f.name))
+            .find(|f| f.index == index)
+    }
+
+    /// Returns every leaf path through nested records, e.g. `address.city`
+    /// or `orders[].total`, with original and serialized names.
+    ///
+    /// Fields whose type derives `FieldKinds` are expanded into their own
+    /// fields; see [`FieldPath`] for the path syntax. A recursive field
+    /// (`children: Vec<Node>` inside `Node`) is returned as a leaf.
+    ///
+    /// ```rust
+    /// use field_kinds::{FieldKinds, FieldKindsExt};
+    ///
+    /// #[derive(FieldKinds)]
+    /// struct Line {
+    ///     total: u64,
+    /// }
+    ///
+    /// #[derive(FieldKinds)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct Order {
+    ///     order_id: u64,
+    ///     line_items: Vec<Line>,
+    /// }
+    ///
+    /// let paths: Vec<_> = Order::field_paths().into_iter().map(|p| p.serialized_path).collect();
+    /// assert_eq!(paths, vec!["orderId", "lineItems[].total"]);
+    /// ```
+    fn field_paths() -> Vec<FieldPath> {
+        paths::field_paths(Schema::of::<Self>())
+    }
+
+    /// Finds a field by a dotted path such as `address.city` or
+    /// `orders[].total`.
+    ///
+    /// Segments may use original or serialized names, and `[]` markers are
+    /// optional. Paths to intermediate record fields (`address`) resolve too.
+    fn find_by_path(path: &str) -> Option<&'static FieldMeta> {
+        paths::find_by_path(Self::FIELDS, path)
+    }
+
     /// Returns the category of a field by name, or `None` if not found.
     fn field_category(name: &str) -> Option<Category> {
         Self::FIELDS
@@ -95,3 +284,44 @@ pub trait FieldKindsExt: VisitFields {
 }
 
 impl<T: VisitFields> FieldKindsExt for T {}
+
+/// Extension trait providing convenient methods for variant introspection.
+///
+/// Automatically implemented for all types that implement [`VisitVariants`].
+pub trait VariantKindsExt: VisitVariants {
+    /// Returns original variant names.
+    fn variant_names() -> Vec<&'static str> {
+        Self::VARIANTS.iter().map(|v| v.name).collect()
+    }
+
+    /// Returns serialized variant names (respecting `#[serde(rename)]`).
+    fn serialized_variant_names() -> Vec<&'static str> {
+        Self::VARIANTS.iter().map(|v| v.serialized_name).collect()
+    }
+
+    /// Checks if a variant with the given name exists.
+    fn has_variant(name: &str) -> bool {
+        Self::VARIANTS.iter().any(|v| v.name == name)
+    }
+
+    /// Finds a variant by its original name.
+    fn find_variant(name: &str) -> Option<&'static VariantMeta> {
+        Self::VARIANTS.iter().find(|v| v.name == name)
+    }
+
+    /// Finds a variant by its serialized name.
+    fn find_variant_by_serialized_name(
+        name: &str,
+    ) -> Option<&'static VariantMeta> {
+        Self::VARIANTS.iter().find(|v| v.serialized_name == name)
+    }
+
+    /// Finds a variant by the name used when deserializing.
+    fn find_variant_by_deserialize_name(
+        name: &str,
+    ) -> Option<&'static VariantMeta> {
+        Self::VARIANTS.iter().find(|v| v.deserialize_name == name)
+    }
+}
+
+impl<T: VisitVariants> VariantKindsExt for T {}
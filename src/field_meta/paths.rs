@@ -0,0 +1,100 @@
+//! Dotted paths through nested records, e.g. `address.city` or
+//! `orders[].total`.
+
+use crate::field_meta::categories::Category;
+use crate::field_meta::visitors::{FieldMeta, Schema};
+
+/// A leaf field reached from the root type through nested records.
+///
+/// Segments are joined with `.`; each collection level between a field and
+/// its nested record adds `[]`, e.g. `orders[].total`. `Option` and other
+/// wrappers don't appear in the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FieldPath {
+    /// Path of original field names.
+    pub path: String,
+    /// Path of serialized field names.
+    pub serialized_path: String,
+    /// Metadata of the leaf field.
+    pub meta: &'static FieldMeta,
+}
+
+/// Collects the leaf paths of `schema`.
+///
+/// A field whose record is already being expanded further up (recursive
+/// types) is emitted as a leaf.
+pub fn field_paths(schema: Schema) -> Vec<FieldPath> {
+    let fields = schema.fields();
+    let mut out = Vec::new();
+    walk(fields, "", "", &mut vec![fields], &mut out);
+    out
+}
+
+fn walk(
+    fields: &'static [FieldMeta],
+    path: &str,
+    serialized_path: &str,
+    ancestors: &mut Vec<&'static [FieldMeta]>,
+    out: &mut Vec<FieldPath>,
+) {
+    for meta in fields {
+        let path = join(path, meta.name);
+        let serialized_path = join(serialized_path, meta.serialized_name);
+        match meta.record_fields() {
+            Some(nested)
+                if !nested.is_empty()
+                    && !ancestors.iter().any(|a| core::ptr::eq(*a, nested)) =>
+            {
+                let brackets = "[]".repeat(
+                    meta.category_chain()
+                        .filter(|c| *c == Category::COLLECTION)
+                        .count(),
+                );
+                ancestors.push(nested);
+                walk(
+                    nested,
+                    &format!("{path}{brackets}"),
+                    &format!("{serialized_path}{brackets}"),
+                    ancestors,
+                    out,
+                );
+                ancestors.pop();
+            }
+            _ => out.push(FieldPath {
+                path,
+                serialized_path,
+                meta,
+            }),
+        }
+    }
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
+/// Resolves a dotted path against `fields`.
+///
+/// Each segment may be the original or the serialized name; trailing `[]`
+/// markers are ignored. Intermediate record fields resolve too.
+pub fn find_by_path(
+    fields: &'static [FieldMeta],
+    path: &str,
+) -> Option<&'static FieldMeta> {
+    let mut fields = fields;
+    let mut found = None;
+    for segment in path.split('.') {
+        let name = segment.trim_end_matches("[]");
+        let meta = fields
+            .iter()
+            .find(|f| f.name == name || f.serialized_name == name)?;
+        fields = meta.record_fields().unwrap_or_default();
+        found = Some(meta);
+    }
+    found
+}
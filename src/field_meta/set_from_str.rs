@@ -0,0 +1,144 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// Error returned by [`SetFieldFromStr::set_field_from_str`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldSetError {
+    /// No field has this Rust or serialized name.
+    UnknownField {
+        /// The name that was looked up.
+        name: String,
+    },
+    /// The value could not be parsed into the field's type.
+    Parse {
+        /// Rust name of the field.
+        field: &'static str,
+        /// The rejected input.
+        value: String,
+        /// The `FromStr` error, formatted.
+        message: String,
+    },
+    /// The field's type does not implement `FromStr`.
+    Unsupported {
+        /// Rust name of the field.
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for FieldSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField { name } => write!(f, "unknown field `{name}`"),
+            Self::Parse {
+                field,
+                value,
+                message,
+            } => write!(
+                f,
+                "invalid value {value:?} for field `{field}`: {message}"
+            ),
+            Self::Unsupported { field } => {
+                write!(f, "field `{field}` cannot be set from a string")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldSetError {}
+
+/// Sets field values from strings, e.g. for config overrides from CLI flags
+/// or environment variables.
+///
+/// Implemented by the derive macro for structs. Values are parsed with the
+/// field type's `FromStr`; fields of generic parameter types are only
+/// supported if the struct bounds them by `FromStr`. Fields written as
+/// `Option<T>` parse `T`, and an empty value sets them to `None`.
+///
+/// ```rust
+/// use field_kinds::{FieldKinds, FieldSetError, SetFieldFromStr};
+///
+/// #[derive(FieldKinds)]
+/// #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// struct DbConfig {
+///     pool_size: u32,
+///     host: String,
+/// }
+///
+/// let mut config = DbConfig { pool_size: 5, host: "localhost".into() };
+/// config.set_field_from_str("POOL_SIZE", "20").unwrap();
+/// config.set_field_from_str("host", "db.internal").unwrap();
+/// assert_eq!(config.pool_size, 20);
+/// assert_eq!(config.host, "db.internal");
+///
+/// let err = config.set_field_from_str("pool_size", "many").unwrap_err();
+/// assert!(matches!(err, FieldSetError::Parse { field: "pool_size", .. }));
+/// ```
+pub trait SetFieldFromStr {
+    /// Parses `value` into the field named `name`, by Rust or serialized
+    /// name. Fields spliced in by `#[serde(flatten)]` are included.
+    ///
+    /// # Errors
+    ///
+    /// [`FieldSetError::UnknownField`] if no field has this name,
+    /// [`FieldSetError::Parse`] if `FromStr` fails and
+    /// [`FieldSetError::Unsupported`] if the field's type has no `FromStr`.
+    fn set_field_from_str(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), FieldSetError>;
+}
+
+/// Zero-sized probe parsing `T` from a string if `T: FromStr`.
+///
+/// Like [`Probe`](super::Probe), the inherent `parse` wins when its bounds
+/// hold and [`ParseFallback::parse`] is used otherwise.
+pub struct ParseProbe<T>(PhantomData<T>);
+
+impl<T: FromStr> ParseProbe<T>
+where
+    T::Err: fmt::Display,
+{
+    /// `T` can be parsed.
+    pub const SUPPORTED: bool = true;
+
+    /// Parses `value` with `T::from_str`.
+    ///
+    /// # Errors
+    ///
+    /// [`FieldSetError::Parse`] with the formatted `FromStr` error.
+    pub fn parse(field: &'static str, value: &str) -> Result<T, FieldSetError> {
+        value.parse().map_err(|err: T::Err| FieldSetError::Parse {
+            field,
+            value: value.to_owned(),
+            message: err.to_string(),
+        })
+    }
+}
+
+/// Parsing used when `T` does not implement `FromStr`.
+pub trait ParseFallback {
+    /// The probed type.
+    type Value;
+
+    /// `T` can't be parsed.
+    const SUPPORTED: bool = false;
+
+    /// Always fails with [`FieldSetError::Unsupported`].
+    ///
+    /// # Errors
+    ///
+    /// Always.
+    fn parse(
+        field: &'static str,
+        _value: &str,
+    ) -> Result<Self::Value, FieldSetError> {
+        Err(FieldSetError::Unsupported { field })
+    }
+}
+
+impl<T> ParseFallback for ParseProbe<T> {
+    type Value = T;
+}
@@ -0,0 +1,100 @@
+/// Value of a key/value tag: `#[field_tags(max_len = 64, unit = "ms")]`.
+///
+/// Compares equal to plain values, so `value == "ms"` or `value == 64`
+/// work directly. Floats compare by bit pattern.
+#[derive(Debug, Clone, Copy)]
+pub enum TagValue {
+    /// String literal: `unit = "ms"`.
+    Str(&'static str),
+    /// Integer literal: `max_len = 64`.
+    Int(i64),
+    /// Float literal: `scale = 0.5`.
+    Float(f64),
+    /// Bool literal: `indexed = true`.
+    Bool(bool),
+}
+
+impl TagValue {
+    /// Returns the string, if this is a string value.
+    pub const fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer, if this is an integer value.
+    pub const fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as a float, for float and integer values.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(f) => Some(*f),
+            Self::Int(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the bool, if this is a bool value.
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for TagValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Str(a), Self::Str(b)) => a == b,
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TagValue {}
+
+impl PartialEq<&str> for TagValue {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == Some(*other)
+    }
+}
+
+impl PartialEq<i64> for TagValue {
+    fn eq(&self, other: &i64) -> bool {
+        self.as_int() == Some(*other)
+    }
+}
+
+impl PartialEq<f64> for TagValue {
+    fn eq(&self, other: &f64) -> bool {
+        matches!(self, Self::Float(f) if f.to_bits() == other.to_bits())
+    }
+}
+
+impl PartialEq<bool> for TagValue {
+    fn eq(&self, other: &bool) -> bool {
+        self.as_bool() == Some(*other)
+    }
+}
+
+impl core::fmt::Display for TagValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Str(s) => f.write_str(s),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::Float(x) => write!(f, "{x}"),
+            Self::Bool(b) => write!(f, "{b}"),
+        }
+    }
+}
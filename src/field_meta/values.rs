@@ -0,0 +1,95 @@
+use core::any::Any;
+
+use crate::field_meta::categories::Category;
+use crate::field_meta::visitors::{FieldMeta, VisitFields};
+
+/// Callbacks for [`VisitValues::visit_values`], one per category.
+///
+/// Every callback receives the field's metadata and its value as `dyn Any`
+/// for downcasting. All callbacks default to [`visit_other`](Self::visit_other),
+/// so a visitor only implements the categories it cares about.
+pub trait FieldValueVisitor {
+    /// Called for every field; dispatches on [`FieldMeta::category`].
+    ///
+    /// Override to see every field before (or instead of) dispatching.
+    fn visit_field(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        match meta.category {
+            c if c == Category::NUMERIC => self.visit_numeric(meta, value),
+            c if c == Category::TEXT => self.visit_text(meta, value),
+            c if c == Category::BOOL => self.visit_bool(meta, value),
+            c if c == Category::OPTIONAL => self.visit_optional(meta, value),
+            c if c == Category::COLLECTION => {
+                self.visit_collection(meta, value);
+            }
+            _ => self.visit_other(meta, value),
+        }
+    }
+
+    /// Numeric field, e.g. `u64` or `f64`.
+    fn visit_numeric(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        self.visit_other(meta, value);
+    }
+
+    /// Text field, e.g. `String` or `&'static str`.
+    fn visit_text(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        self.visit_other(meta, value);
+    }
+
+    /// `bool` field.
+    fn visit_bool(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        self.visit_other(meta, value);
+    }
+
+    /// `Option<T>` field; see [`FieldMeta::inner`] for the element category.
+    fn visit_optional(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        self.visit_other(meta, value);
+    }
+
+    /// Collection field; see [`FieldMeta::inner`] for the element category.
+    fn visit_collection(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        self.visit_other(meta, value);
+    }
+
+    /// Any other field, and the default for every other callback.
+    fn visit_other(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let _ = (meta, value);
+    }
+}
+
+/// Drives a [`FieldValueVisitor`] over a value's fields.
+///
+/// Implemented by the derive macro alongside [`FieldAccess`](crate::FieldAccess),
+/// i.e. for structs without lifetime parameters.
+///
+/// ```rust
+/// use core::any::Any;
+/// use field_kinds::{FieldKinds, FieldMeta, FieldValueVisitor, VisitValues};
+///
+/// #[derive(FieldKinds)]
+/// struct Order {
+///     id: u64,
+///     note: String,
+///     paid: bool,
+/// }
+///
+/// #[derive(Default)]
+/// struct TextLengths(Vec<(&'static str, usize)>);
+///
+/// impl FieldValueVisitor for TextLengths {
+///     fn visit_text(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+///         if let Some(text) = value.downcast_ref::<String>() {
+///             self.0.push((meta.name, text.len()));
+///         }
+///     }
+/// }
+///
+/// let order = Order { id: 1, note: "leave at door".into(), paid: true };
+/// let mut lengths = TextLengths::default();
+/// order.visit_values(&mut lengths);
+/// assert_eq!(lengths.0, vec![("note", 13)]);
+/// ```
+pub trait VisitValues: VisitFields {
+    /// Calls [`FieldValueVisitor::visit_field`] for every field in
+    /// [`FIELDS`](VisitFields::FIELDS), in order.
+    fn visit_values(&self, visitor: &mut impl FieldValueVisitor);
+}
@@ -0,0 +1,73 @@
+use crate::field_meta::visitors::FieldMeta;
+
+/// Shape of an enum variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariantKind {
+    /// Variant without fields: `Deleted`.
+    Unit,
+    /// Variant with positional fields: `Moved(u32, u32)`.
+    Tuple,
+    /// Variant with named fields: `Created { id: u64 }`.
+    Struct,
+}
+
+/// Runtime-accessible metadata for a single enum variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct VariantMeta {
+    /// Original variant name in Rust code.
+    pub name: &'static str,
+    /// Serialized name (may differ due to `#[serde(rename)]` or `rename_all`).
+    pub serialized_name: &'static str,
+    /// Name serde reads when deserializing.
+    pub deserialize_name: &'static str,
+    /// Shape of the variant.
+    pub kind: VariantKind,
+    /// Metadata for the variant's fields, empty for unit variants.
+    ///
+    /// Tuple variant fields are named by position: `"0"`, `"1"`, ...
+    pub fields: &'static [FieldMeta],
+}
+
+impl VariantMeta {
+    /// Creates a new `VariantMeta` instance.
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        serialized_name: &'static str,
+        kind: VariantKind,
+        fields: &'static [FieldMeta],
+    ) -> Self {
+        Self {
+            name,
+            serialized_name,
+            deserialize_name: serialized_name,
+            kind,
+            fields,
+        }
+    }
+
+    /// Sets the name used when deserializing.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_deserialize_name(mut self, name: &'static str) -> Self {
+        self.deserialize_name = name;
+        self
+    }
+
+    /// Finds a field of this variant by its original name.
+    pub fn find_field(&self, name: &str) -> Option<&'static FieldMeta> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+/// Trait for enums that provide static variant metadata.
+///
+/// This trait is automatically implemented by the derive macro for enums.
+pub trait VisitVariants {
+    /// Static slice containing metadata for all variants.
+    const VARIANTS: &'static [VariantMeta];
+
+    /// Number of variants in the enum (compile-time constant).
+    const VARIANT_COUNT: usize = Self::VARIANTS.len();
+}
@@ -1,4 +1,36 @@
-use crate::field_meta::categories::Category;
+use crate::field_meta::categories::{
+    Category, NestedCategory, NumericClass, NumericKind,
+};
+use crate::field_meta::tags::TagValue;
+
+/// Lazily resolved field metadata of a type deriving `FieldKinds`.
+///
+/// Holds a function rather than a slice so that recursive types
+/// (`struct Node { children: Vec<Node> }`) don't form a const cycle.
+#[derive(Debug, Clone, Copy)]
+pub struct Schema(fn() -> &'static [FieldMeta]);
+
+impl Schema {
+    /// Schema of `T`.
+    pub const fn of<T: VisitFields + ?Sized>() -> Self {
+        Self(fields_of::<T>)
+    }
+
+    /// Returns the type's field metadata.
+    pub fn fields(self) -> &'static [FieldMeta] {
+        (self.0)()
+    }
+}
+
+// Compared by function identity: comparing the fields themselves would
+// recurse forever for recursive types.
+impl PartialEq for Schema {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::fn_addr_eq(self.0, other.0)
+    }
+}
+
+impl Eq for Schema {}
 
 /// Runtime-accessible metadata for a single field.
 ///
@@ -9,11 +41,54 @@ pub struct FieldMeta {
     /// Original field name in Rust code.
     pub name: &'static str,
     /// Serialized name (may differ due to `#[serde(rename)]` or `rename_all`).
+    ///
+    /// This is the name serde writes; see [`deserialize_name`](Self::deserialize_name)
+    /// for the name it reads.
     pub serialized_name: &'static str,
+    /// Name serde reads when deserializing. Differs from `serialized_name`
+    /// only with `rename(deserialize = "...")` or `rename_all(deserialize = "...")`.
+    pub deserialize_name: &'static str,
+    /// Additional names accepted when deserializing, from `#[serde(alias = "...")]`.
+    pub aliases: &'static [&'static str],
     /// Type category.
     pub category: Category,
+    /// Element category for containers (`T` in `Option<T>`, `Vec<T>`, ...;
+    /// the value type for maps).
+    pub inner: Option<&'static NestedCategory>,
+    /// Key category for maps.
+    pub key: Option<&'static NestedCategory>,
+    /// Integer, float or decimal for numeric fields.
+    pub numeric_class: Option<NumericClass>,
+    /// Signedness and width for primitive numeric fields.
+    pub numeric_kind: Option<NumericKind>,
+    /// Field metadata of the field's type, if it derives `FieldKinds`.
+    ///
+    /// See [`record_fields`](Self::record_fields) to also look through
+    /// `Option`, collections and other containers.
+    pub schema: Option<Schema>,
     /// Custom tags added via `#[field_tags(...)]`.
     pub tags: &'static [&'static str],
+    /// Key/value tags added via `#[field_tags(key = value)]`.
+    pub tag_values: &'static [(&'static str, TagValue)],
+    /// Declaration position in the struct or variant, counting skipped fields.
+    ///
+    /// For flattened entries this is the position in the inner struct.
+    pub index: usize,
+    /// Name of the `#[serde(flatten)]` field this entry was spliced from,
+    /// or `None` for the struct's own fields.
+    ///
+    /// Flattened maps and `Option`s have no known fields to splice; they
+    /// stay a single entry naming itself here.
+    pub flattened_from: Option<&'static str>,
+    /// `true` if serde never writes this field
+    /// (`#[serde(skip)]` or `#[serde(skip_serializing)]`).
+    pub skip_serializing: bool,
+    /// `true` if serde never reads this field
+    /// (`#[serde(skip)]` or `#[serde(skip_deserializing)]`).
+    pub skip_deserializing: bool,
+    /// `true` if [`SetFieldFromStr`](crate::SetFieldFromStr) can parse this
+    /// field: its type (`T` for `Option<T>`) implements `FromStr`.
+    pub settable_from_str: bool,
 }
 
 impl FieldMeta {
@@ -28,11 +103,149 @@ impl FieldMeta {
         Self {
             name,
             serialized_name,
+            deserialize_name: serialized_name,
+            aliases: &[],
             category,
+            inner: None,
+            key: None,
+            numeric_class: None,
+            numeric_kind: None,
+            schema: None,
             tags,
+            tag_values: &[],
+            index: 0,
+            flattened_from: None,
+            skip_serializing: false,
+            skip_deserializing: false,
+            settable_from_str: true,
         }
     }
 
+    /// Sets the declaration position of the field.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Sets the name used when deserializing.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_deserialize_name(mut self, name: &'static str) -> Self {
+        self.deserialize_name = name;
+        self
+    }
+
+    /// Sets the names accepted via `#[serde(alias)]`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_aliases(
+        mut self,
+        aliases: &'static [&'static str],
+    ) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Sets the key/value tags.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_tag_values(
+        mut self,
+        tag_values: &'static [(&'static str, TagValue)],
+    ) -> Self {
+        self.tag_values = tag_values;
+        self
+    }
+
+    /// Sets the element and key categories of the field's type.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_nested(
+        mut self,
+        inner: Option<&'static NestedCategory>,
+        key: Option<&'static NestedCategory>,
+    ) -> Self {
+        self.inner = inner;
+        self.key = key;
+        self
+    }
+
+    /// Sets the numeric class of the field's type.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_numeric_class(
+        mut self,
+        numeric_class: Option<NumericClass>,
+    ) -> Self {
+        self.numeric_class = numeric_class;
+        self
+    }
+
+    /// Sets the numeric kind of the field's type.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_numeric_kind(
+        mut self,
+        numeric_kind: Option<NumericKind>,
+    ) -> Self {
+        self.numeric_kind = numeric_kind;
+        self
+    }
+
+    /// Sets the field metadata of the field's type.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_schema(mut self, schema: Option<Schema>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Records the flattened field this entry was spliced from.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_flattened_from(mut self, field: &'static str) -> Self {
+        self.flattened_from = Some(field);
+        self
+    }
+
+    /// Returns `true` if this entry comes from a `#[serde(flatten)]` field.
+    pub const fn is_flattened(&self) -> bool {
+        self.flattened_from.is_some()
+    }
+
+    /// Records serde's per-direction skip flags.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_serde_skip(
+        mut self,
+        skip_serializing: bool,
+        skip_deserializing: bool,
+    ) -> Self {
+        self.skip_serializing = skip_serializing;
+        self.skip_deserializing = skip_deserializing;
+        self
+    }
+
+    /// Records whether the field's type implements `FromStr`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_settable_from_str(mut self, settable: bool) -> Self {
+        self.settable_from_str = settable;
+        self
+    }
+
+    /// Returns `true` if serde writes this field when serializing.
+    pub const fn is_serialized(&self) -> bool {
+        !self.skip_serializing
+    }
+
+    /// Returns `true` if serde reads this field when deserializing.
+    pub const fn is_deserialized(&self) -> bool {
+        !self.skip_deserializing
+    }
+
     /// Checks if this field has the given tag.
     pub const fn has_tag(&self, tag: &str) -> bool {
         let mut i = 0;
@@ -45,6 +258,74 @@ impl FieldMeta {
         false
     }
 
+    /// Returns the value of the key/value tag `key`, e.g. `TagValue::Int(64)`
+    /// for `#[field_tags(max_len = 64)]`. The first value wins if a key is
+    /// repeated.
+    pub const fn tag_value(&self, key: &str) -> Option<TagValue> {
+        find_tag_value(self.tag_values, key)
+    }
+
+    /// Returns the element category, e.g. [`Category::TEXT`] for `Option<String>`.
+    pub const fn inner_category(&self) -> Option<Category> {
+        match self.inner {
+            Some(inner) => Some(inner.category),
+            None => None,
+        }
+    }
+
+    /// Returns the key category for maps.
+    pub const fn key_category(&self) -> Option<Category> {
+        match self.key {
+            Some(key) => Some(key.category),
+            None => None,
+        }
+    }
+
+    /// Returns signedness and width for primitive numeric fields, `None` for
+    /// other fields and decimals.
+    pub const fn numeric_kind(&self) -> Option<NumericKind> {
+        self.numeric_kind
+    }
+
+    /// Returns the fields of the nested record: the field's own type, or
+    /// the innermost element type for containers such as
+    /// `Option<Vec<Address>>`. `None` if no such type derives `FieldKinds`.
+    pub fn record_fields(&self) -> Option<&'static [Self]> {
+        let nested = core::iter::successors(self.inner, |node| node.inner)
+            .map(|node| node.schema);
+        core::iter::once(self.schema)
+            .chain(nested)
+            .flatten()
+            .next()
+            .map(Schema::fields)
+    }
+
+    /// Iterates over the field's category and its nested element categories,
+    /// outermost first: `Option<Vec<String>>` yields optional, collection, text.
+    pub fn category_chain(&self) -> impl Iterator<Item = Category> {
+        core::iter::once(self.category)
+            .chain(self.inner.into_iter().flat_map(NestedCategory::chain))
+    }
+
+    /// Checks if `name` is any of this field's names: the Rust name,
+    /// serialized name, deserialize name or one of its aliases.
+    pub const fn has_name(&self, name: &str) -> bool {
+        if const_str_eq(self.name, name)
+            || const_str_eq(self.serialized_name, name)
+            || const_str_eq(self.deserialize_name, name)
+        {
+            return true;
+        }
+        let mut i = 0;
+        while i < self.aliases.len() {
+            if const_str_eq(self.aliases[i], name) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
     /// Checks if this field has the given category.
     pub const fn has_category(&self, category: Category) -> bool {
         const_str_eq(self.category.name(), category.name())
@@ -85,6 +366,110 @@ const fn const_str_eq(a: &str, b: &str) -> bool {
     true
 }
 
+/// Runtime-accessible metadata for a type deriving `FieldKinds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TypeMeta {
+    /// Type name in Rust code, without generic parameters.
+    pub name: &'static str,
+    /// Serialized name (differs with `#[serde(rename)]` on the type).
+    pub serialized_name: &'static str,
+    /// Name serde reads when deserializing.
+    pub deserialize_name: &'static str,
+    /// `module_path!()` of the module declaring the type.
+    pub module_path: &'static str,
+    /// Custom tags added via `#[field_tags(...)]` on the type.
+    pub tags: &'static [&'static str],
+    /// Key/value tags added via `#[field_tags(key = value)]` on the type.
+    pub tag_values: &'static [(&'static str, TagValue)],
+    /// `true` with `#[field_kinds(exclude_serde_skipped)]`: name lists leave
+    /// out fields serde skips in that direction.
+    pub exclude_serde_skipped: bool,
+}
+
+impl TypeMeta {
+    /// Creates a new `TypeMeta` instance.
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        serialized_name: &'static str,
+        module_path: &'static str,
+        tags: &'static [&'static str],
+    ) -> Self {
+        Self {
+            name,
+            serialized_name,
+            deserialize_name: serialized_name,
+            module_path,
+            tags,
+            tag_values: &[],
+            exclude_serde_skipped: false,
+        }
+    }
+
+    /// Sets the name used when deserializing.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_deserialize_name(mut self, name: &'static str) -> Self {
+        self.deserialize_name = name;
+        self
+    }
+
+    /// Sets the key/value tags.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_tag_values(
+        mut self,
+        tag_values: &'static [(&'static str, TagValue)],
+    ) -> Self {
+        self.tag_values = tag_values;
+        self
+    }
+
+    /// Records the `#[field_kinds(exclude_serde_skipped)]` opt-in.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn with_exclude_serde_skipped(mut self, exclude: bool) -> Self {
+        self.exclude_serde_skipped = exclude;
+        self
+    }
+
+    /// Returns the value of the key/value tag `key`.
+    pub const fn tag_value(&self, key: &str) -> Option<TagValue> {
+        find_tag_value(self.tag_values, key)
+    }
+
+    /// Checks if the type has the given tag.
+    pub const fn has_tag(&self, tag: &str) -> bool {
+        let mut i = 0;
+        while i < self.tags.len() {
+            if const_str_eq(self.tags[i], tag) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+const fn find_tag_value(
+    values: &[(&'static str, TagValue)],
+    key: &str,
+) -> Option<TagValue> {
+    let mut i = 0;
+    while i < values.len() {
+        if const_str_eq(values[i].0, key) {
+            return Some(values[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn fields_of<T: VisitFields + ?Sized>() -> &'static [FieldMeta] {
+    T::FIELDS
+}
+
 /// Trait for types that provide static field metadata.
 ///
 /// This trait is automatically implemented by the derive macro.
@@ -92,6 +477,9 @@ pub trait VisitFields {
     /// Static slice containing metadata for all fields.
     const FIELDS: &'static [FieldMeta];
 
+    /// Metadata of the type itself.
+    const TYPE: TypeMeta;
+
     /// Number of fields in the struct (compile-time constant).
     const FIELD_COUNT: usize = Self::FIELDS.len();
 }
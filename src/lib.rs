@@ -10,9 +10,20 @@
 //!
 //! - **Field names**: Get field names as `&'static str`
 //! - **Serialized names**: Supports `#[serde(rename)]` and `#[serde(rename_all)]`
-//! - **Type categories**: Automatic categorization (numeric, text, bool, optional, collection)
-//! - **Custom tags**: Add arbitrary tags to fields via `#[field_tags("tag1", "tag2")]`
-//! - **Static metadata**: All field info available as `const FIELDS: &'static [FieldMeta]`
+//! - **Type categories**: Automatic categorization (numeric, text, bool, optional, collection, temporal, identifier, binary, record),
+//!   including the element and key categories of containers
+//! - **Custom tags**: Add arbitrary tags to fields via `#[field_tags("tag1", "tag2")]`,
+//!   or key/value tags via `#[field_tags(max_len = 64, unit = "ms")]`
+//! - **Static metadata**: All field info available as `const FIELDS: &'static [FieldMeta]`,
+//!   the type's own name, module path and tags as `const TYPE: TypeMeta`
+//! - **Nested structs**: Derived structs are [`Category::RECORD`] with their fields reachable via
+//!   [`FieldMeta::record_fields`] and dotted paths via [`FieldKindsExt::field_paths`]
+//! - **Value access**: Read and write field values by name via [`FieldAccess`],
+//!   or walk them with a category-aware [`FieldValueVisitor`] via [`VisitValues`];
+//!   parse values into fields with [`SetFieldFromStr`]
+//! - **Environment config**: Load structs from `PREFIX_FIELD_NAME` variables via [`FromEnv`]
+//! - **Diffs**: List changed fields between two values via [`FieldDiff`], redacting sensitive ones
+//! - **Enums**: Per-variant metadata via `const VARIANTS: &'static [VariantMeta]`
 //! - **Zero runtime cost**: All metadata is computed at compile time
 //!
 //! # Example
@@ -51,22 +62,124 @@
 //! assert_eq!(User::FIELDS.len(), 4);
 //! ```
 //!
+//! # Tuple and unit structs
+//!
+//! Tuple struct fields are named by position and carry their index;
+//! unit structs have an empty `FIELDS`:
+//!
+//! ```rust
+//! use field_kinds::{FieldKinds, FieldKindsExt, VisitFields};
+//!
+//! #[derive(FieldKinds)]
+//! struct Point(f64, f64);
+//!
+//! #[derive(FieldKinds)]
+//! struct Marker;
+//!
+//! assert_eq!(Point::field_names(), vec!["0", "1"]);
+//! assert_eq!(Point::FIELDS[1].index, 1);
+//! assert_eq!(Marker::FIELD_COUNT, 0);
+//! ```
+//!
+//! # Enums
+//!
+//! Deriving on an enum implements [`VisitVariants`] instead of [`VisitFields`]:
+//!
+//! ```rust
+//! use field_kinds::{FieldKinds, VariantKind, VariantKindsExt, VisitVariants};
+//!
+//! #[derive(FieldKinds)]
+//! #[serde(rename_all = "snake_case")]
+//! enum Event {
+//!     Created { id: u64, at: String },
+//!     Moved(u32, u32),
+//!     Deleted,
+//! }
+//!
+//! assert_eq!(Event::serialized_variant_names(), vec!["created", "moved", "deleted"]);
+//!
+//! let created = Event::find_variant("Created").unwrap();
+//! assert_eq!(created.kind, VariantKind::Struct);
+//! assert_eq!(created.fields[0].name, "id");
+//! assert_eq!(Event::VARIANTS[1].fields[1].name, "1");
+//! ```
+//!
+//! # Cargo features
+//!
+//! - `bigdecimal` - [`Categorized`] impl (as [`Numeric`], [`NumericClass::Decimal`]) for `bigdecimal::BigDecimal`
+//! - `bytes` - [`Categorized`] impls (as [`Binary`]) for `bytes::Bytes` and `bytes::BytesMut`
+//! - `chrono` - [`Categorized`] impls (as [`Temporal`]) for `chrono` date, time and duration types
+//! - `rust_decimal` - [`Categorized`] impl (as [`Numeric`], [`NumericClass::Decimal`]) for `rust_decimal::Decimal`
+//! - `time` - [`Categorized`] impls (as [`Temporal`]) for `time` date, time and duration types
+//! - `uuid` - [`Categorized`] impls (as [`Identifier`]) for `uuid::Uuid` and `uuid::NonNilUuid`
+//!
 //! # Attributes
 //!
 //! ## Struct-level
 //!
 //! - `#[serde(rename_all = "...")]` - Apply case conversion to serialized names
+//!   (`rename_all(serialize = "...", deserialize = "...")` sets each direction)
+//! - `#[serde(rename = "...")]` - Serialized type name, see [`TypeMeta`]
+//! - `#[field_tags("tag1", "tag2")]` - Add custom tags to the type, see [`TypeMeta::has_tag`]
+//! - `#[field_kinds(exclude_serde_skipped)]` - Exclude fields serde skips in both directions,
+//!   and leave one-direction skips out of the serialized or deserialize names
+//! - `#[field_kinds(transparent)]` - Implement [`Categorized`] for a single-field wrapper by forwarding to the wrapped type
+//! - `#[field_kinds(unknown_fallback)]` - Categorize field types without a [`Categorized`] impl
+//!   as [`Category::UNKNOWN`] instead of failing to compile. Not supported on generic types
+//! - `#[field_kinds(skip_categorized)]` - Don't implement [`Categorized`] as [`Record`], e.g. to keep a manual impl
+//! - `#[field_kinds(env)]` - Implement [`FromEnv`], loading fields from environment variables
+//! - `#[field_kinds(diff)]` - Implement [`FieldDiff`], comparing two values field by field
+//!
+//! ## Enum-level
+//!
+//! - `#[serde(rename_all = "...")]` - Apply case conversion to variant names
+//! - `#[serde(rename_all_fields = "...")]` - Apply case conversion to the fields of every struct variant
+//!
+//! ## Variant-level
+//!
+//! - `#[serde(rename = "...")]` - Override serialized name for a variant
+//! - `#[serde(rename_all = "...")]` - Apply case conversion to the variant's fields
+//! - `#[field_kinds(skip)]` - Skip a variant from introspection
 //!
 //! ## Field-level
 //!
 //! - `#[serde(rename = "...")]` - Override serialized name for a field
+//! - `#[serde(rename(serialize = "...", deserialize = "..."))]` - Override names per direction
+//! - `#[serde(alias = "...")]` - Record an alternative name, see [`FieldKindsExt::find_by_any_name`]
 //! - `#[field_tags("tag1", "tag2")]` - Add custom tags to a field
+//! - `#[field_tags(max_len = 64, unit = "ms")]` - Add key/value tags with string, integer, float or bool
+//!   values, see [`FieldMeta::tag_value`] and [`FieldKindsExt::fields_with_tag_value`]
 //! - `#[field_kinds(skip)]` - Skip a field from introspection
+//! - `#[field_kinds(category = "numeric")]` or `#[field_kinds(category = path::Marker)]` -
+//!   Set the category to a built-in name or a custom [`TypeCategory`]; the field type
+//!   need not implement [`Categorized`]
+//! - `#[field_kinds(identifier)]` - Categorize the field as [`Category::IDENTIFIER`],
+//!   see [`FieldKindsExt::identifier_fields`]
+//! - `#[field_kinds(binary)]` - Categorize the field as [`Category::BINARY`], e.g. `Vec<u8>`.
+//!   Implied by `#[serde(with = "serde_bytes")]` unless a category is set explicitly
+//! - `#[serde(flatten)]` - Splice the inner type's fields in place of this field.
+//!   The inner type must derive `FieldKinds`; not supported on generic types.
+//!   Flattened maps and `Option`s stay a single field
+//! - `#[serde(skip)]`, `#[serde(skip_serializing)]`, `#[serde(skip_deserializing)]` -
+//!   Recorded per direction, see [`FieldMeta::is_serialized`] and [`FieldMeta::is_deserialized`]
 
 mod field_meta;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::field_meta::{
+        EnvFallback, EnvProbe, Fallback, ParseFallback, ParseProbe, Probe,
+        flatten_fields, flattened_len,
+    };
+}
+
 pub use field_kinds_derive::FieldKinds;
 pub use field_meta::{
-    Bool, Categorized, Category, Collection, FieldInfo, FieldKindsExt,
-    FieldMeta, Numeric, Optional, Text, TypeCategory, Unknown, VisitFields,
+    Binary, Bool, Categorized, Category, Collection, EnvError, EnvVarError,
+    FieldAccess, FieldChange, FieldDiff, FieldInfo, FieldKindsExt, FieldMeta,
+    FieldPath, FieldSetError, FieldValueVisitor, FromEnv, Identifier,
+    NestedCategory, Numeric, NumericClass, NumericKind, Optional, REDACTED,
+    Record, Schema, SetFieldFromStr, TagValue, Temporal, Text, TypeCategory,
+    TypeMeta, Unknown, VariantKind, VariantKindsExt, VariantMeta, VisitFields,
+    VisitValues, VisitVariants, env_var_name,
 };
@@ -1,17 +1,23 @@
 #![allow(clippy::needless_pass_by_value)]
 
 use field_kinds::{
-    Bool, Categorized, Category, Collection, Numeric, Optional, Text,
-    TypeCategory, Unknown,
+    Bool, Categorized, Category, Collection, FieldKinds, FieldKindsExt,
+    NestedCategory, Numeric, NumericClass, NumericKind, Optional, Temporal,
+    Text, TypeCategory, Unknown,
 };
 use rstest::rstest;
 use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::num::{
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
     NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    Saturating, Wrapping,
 };
-use std::sync::Arc;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime};
 
 const fn category_of<T: Categorized>() -> Category {
     <T::Category as TypeCategory>::CATEGORY
@@ -23,6 +29,7 @@ const fn category_of<T: Categorized>() -> Category {
 #[case::bool(Bool::NAME, "bool")]
 #[case::optional(Optional::NAME, "optional")]
 #[case::collection(Collection::NAME, "collection")]
+#[case::temporal(Temporal::NAME, "temporal")]
 #[case::unknown(Unknown::NAME, "unknown")]
 fn category_names(#[case] actual: &str, #[case] expected: &str) {
     assert_eq!(actual, expected);
@@ -66,7 +73,7 @@ fn numeric_types(#[case] category: Category) {
 #[case::char(category_of::<char>())]
 #[case::cow_str(category_of::<Cow<'_, str>>())]
 #[case::arc_str(category_of::<Arc<str>>())]
-#[case::rc_str(category_of::<std::rc::Rc<str>>())]
+#[case::rc_str(category_of::<Rc<str>>())]
 fn text_types(#[case] category: Category) {
     assert_eq!(category, Category::TEXT);
 }
@@ -85,6 +92,14 @@ fn optional_types(#[case] category: Category) {
     assert_eq!(category, Category::OPTIONAL);
 }
 
+#[rstest]
+#[case::system_time(category_of::<SystemTime>())]
+#[case::instant(category_of::<Instant>())]
+#[case::duration(category_of::<Duration>())]
+fn temporal_types(#[case] category: Category) {
+    assert_eq!(category, Category::TEMPORAL);
+}
+
 #[rstest]
 #[case::vec(category_of::<Vec<i32>>())]
 #[case::array(category_of::<[i32; 5]>())]
@@ -96,3 +111,181 @@ fn optional_types(#[case] category: Category) {
 fn collection_types(#[case] category: Category) {
     assert_eq!(category, Category::COLLECTION);
 }
+
+#[allow(dead_code)]
+#[derive(FieldKinds)]
+struct Nesting {
+    scalar: u32,
+    option_text: Option<String>,
+    vec_bool: Vec<bool>,
+    array: [u8; 4],
+    set: BTreeSet<String>,
+    option_vec_text: Option<Vec<String>>,
+    hashmap: HashMap<String, Vec<i32>>,
+    btreemap: BTreeMap<String, Vec<i32>>,
+    box_option_text: Box<Option<String>>,
+    cow_slice: Cow<'static, [String]>,
+    vec_arc_str: Vec<Arc<str>>,
+    option_f32: Option<f32>,
+}
+
+fn chain_of(name: &str) -> Vec<Category> {
+    Nesting::find_by_name(name)
+        .unwrap()
+        .category_chain()
+        .collect()
+}
+
+#[rstest]
+#[case::scalar(chain_of("scalar"), vec![Category::NUMERIC])]
+#[case::option_text(
+    chain_of("option_text"),
+    vec![Category::OPTIONAL, Category::TEXT]
+)]
+#[case::vec_bool(
+    chain_of("vec_bool"),
+    vec![Category::COLLECTION, Category::BOOL]
+)]
+#[case::array(
+    chain_of("array"),
+    vec![Category::COLLECTION, Category::NUMERIC]
+)]
+#[case::set(
+    chain_of("set"),
+    vec![Category::COLLECTION, Category::TEXT]
+)]
+#[case::option_vec_text(
+    chain_of("option_vec_text"),
+    vec![Category::OPTIONAL, Category::COLLECTION, Category::TEXT]
+)]
+fn nested_category_chain(
+    #[case] chain: Vec<Category>,
+    #[case] expected: Vec<Category>,
+) {
+    assert_eq!(chain, expected);
+}
+
+#[rstest]
+#[case::hashmap("hashmap")]
+#[case::btreemap("btreemap")]
+fn map_key_and_value_categories(#[case] name: &str) {
+    let field = Nesting::find_by_name(name).unwrap();
+    assert_eq!(field.key_category(), Some(Category::TEXT));
+    let value: Vec<_> = field.inner.unwrap().chain().collect();
+    assert_eq!(value, vec![Category::COLLECTION, Category::NUMERIC]);
+}
+
+struct Foreign;
+
+#[test]
+fn containers_do_not_require_categorized_elements() {
+    assert_eq!(category_of::<Vec<Foreign>>(), Category::COLLECTION);
+    assert_eq!(category_of::<Option<Foreign>>(), Category::OPTIONAL);
+    assert_eq!(category_of::<HashMap<Foreign, u8>>(), Category::COLLECTION);
+    assert!(<Vec<u8> as Categorized>::INNER.is_none());
+}
+
+#[test]
+fn scalars_have_no_inner_or_key() {
+    let node = NestedCategory::of::<String>();
+    assert!(node.inner.is_none());
+    assert!(node.key.is_none());
+    assert!(<HashMap<String, u8> as Categorized>::KEY.is_none());
+}
+
+#[rstest]
+#[case::box_u64(category_of::<Box<u64>>(), Category::NUMERIC)]
+#[case::arc_string(category_of::<Arc<String>>(), Category::TEXT)]
+#[case::rc_vec(category_of::<Rc<Vec<u8>>>(), Category::COLLECTION)]
+#[case::cow_string(category_of::<Cow<'_, String>>(), Category::TEXT)]
+#[case::cow_slice(category_of::<Cow<'_, [u8]>>(), Category::COLLECTION)]
+#[case::cell(category_of::<Cell<bool>>(), Category::BOOL)]
+#[case::refcell(category_of::<RefCell<Option<i32>>>(), Category::OPTIONAL)]
+#[case::mutex(category_of::<Mutex<String>>(), Category::TEXT)]
+#[case::rwlock(category_of::<RwLock<u8>>(), Category::NUMERIC)]
+#[case::wrapping(category_of::<Wrapping<u32>>(), Category::NUMERIC)]
+#[case::saturating(category_of::<Saturating<i16>>(), Category::NUMERIC)]
+#[case::reverse(category_of::<Reverse<u32>>(), Category::NUMERIC)]
+#[case::nested(category_of::<Arc<Mutex<Box<f64>>>>(), Category::NUMERIC)]
+fn wrapper_types_forward_category(
+    #[case] category: Category,
+    #[case] expected: Category,
+) {
+    assert_eq!(category, expected);
+}
+
+#[test]
+fn wrapper_types_forward_inner_category() {
+    assert_eq!(
+        chain_of("box_option_text"),
+        vec![Category::OPTIONAL, Category::TEXT]
+    );
+    assert_eq!(
+        chain_of("cow_slice"),
+        vec![Category::COLLECTION, Category::TEXT]
+    );
+    assert_eq!(
+        chain_of("vec_arc_str"),
+        vec![Category::COLLECTION, Category::TEXT]
+    );
+}
+
+#[rstest]
+#[case::u8(NestedCategory::of::<u8>(), Some(NumericClass::Integer))]
+#[case::i128(NestedCategory::of::<i128>(), Some(NumericClass::Integer))]
+#[case::usize(NestedCategory::of::<usize>(), Some(NumericClass::Integer))]
+#[case::non_zero(NestedCategory::of::<NonZeroU32>(), Some(NumericClass::Integer))]
+#[case::f32(NestedCategory::of::<f32>(), Some(NumericClass::Float))]
+#[case::f64(NestedCategory::of::<f64>(), Some(NumericClass::Float))]
+#[case::wrapping(NestedCategory::of::<Wrapping<u8>>(), Some(NumericClass::Integer))]
+#[case::boxed(NestedCategory::of::<Box<f64>>(), Some(NumericClass::Float))]
+#[case::string(NestedCategory::of::<String>(), None)]
+#[case::option(NestedCategory::of::<Option<u8>>(), None)]
+fn numeric_class(
+    #[case] node: NestedCategory,
+    #[case] expected: Option<NumericClass>,
+) {
+    assert_eq!(node.numeric_class, expected);
+}
+
+#[test]
+fn numeric_class_of_element_type() {
+    let field = Nesting::find_by_name("option_f32").unwrap();
+    assert_eq!(field.inner.unwrap().numeric_class, Some(NumericClass::Float));
+}
+
+#[rstest]
+#[case::u8(<u8 as Categorized>::NUMERIC_KIND, Some(NumericKind::unsigned(8)))]
+#[case::u128(<u128 as Categorized>::NUMERIC_KIND, Some(NumericKind::unsigned(128)))]
+#[case::usize(
+    <usize as Categorized>::NUMERIC_KIND,
+    Some(NumericKind::unsigned(usize::BITS))
+)]
+#[case::i16(<i16 as Categorized>::NUMERIC_KIND, Some(NumericKind::signed(16)))]
+#[case::i64(<i64 as Categorized>::NUMERIC_KIND, Some(NumericKind::signed(64)))]
+#[case::f32(<f32 as Categorized>::NUMERIC_KIND, Some(NumericKind::float(32)))]
+#[case::f64(<f64 as Categorized>::NUMERIC_KIND, Some(NumericKind::float(64)))]
+#[case::non_zero_i32(
+    <NonZeroI32 as Categorized>::NUMERIC_KIND,
+    Some(NumericKind::signed(32))
+)]
+#[case::saturating(
+    <Saturating<u16> as Categorized>::NUMERIC_KIND,
+    Some(NumericKind::unsigned(16))
+)]
+#[case::text(<String as Categorized>::NUMERIC_KIND, None)]
+fn numeric_kind(
+    #[case] kind: Option<NumericKind>,
+    #[case] expected: Option<NumericKind>,
+) {
+    assert_eq!(kind, expected);
+}
+
+#[test]
+fn numeric_kind_constructors() {
+    let float = NumericKind::float(64);
+    assert!(float.signed && float.float);
+    let unsigned = NumericKind::unsigned(8);
+    assert!(!unsigned.signed && !unsigned.float);
+    assert_eq!(unsigned.bits, 8);
+}
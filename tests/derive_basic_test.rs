@@ -96,3 +96,89 @@ fn collection_struct_categories() {
         vec!["count"]
     );
 }
+
+#[derive(FieldKinds)]
+struct Profile {
+    nickname: Option<String>,
+    age: Option<u8>,
+    emails: Vec<String>,
+    scores: HashMap<String, f64>,
+    nested: Option<Vec<String>>,
+    active: bool,
+}
+
+#[test]
+fn inner_categories() {
+    let nickname = Profile::find_by_name("nickname").unwrap();
+    assert_eq!(nickname.inner_category(), Some(Category::TEXT));
+    assert_eq!(nickname.key_category(), None);
+
+    let active = Profile::find_by_name("active").unwrap();
+    assert_eq!(active.inner_category(), None);
+}
+
+#[test]
+fn map_key_and_value_categories() {
+    let scores = Profile::find_by_name("scores").unwrap();
+    assert_eq!(scores.key_category(), Some(Category::TEXT));
+    assert_eq!(scores.inner_category(), Some(Category::NUMERIC));
+}
+
+#[test]
+fn category_chain() {
+    let nested = Profile::find_by_name("nested").unwrap();
+    let chain: Vec<_> = nested.category_chain().collect();
+    assert_eq!(
+        chain,
+        vec![Category::OPTIONAL, Category::COLLECTION, Category::TEXT]
+    );
+}
+
+/// A type without a `Categorized` impl.
+struct Foreign(u8);
+
+#[derive(FieldKinds)]
+struct Imported {
+    items: Vec<Foreign>,
+    maybe: Option<Foreign>,
+    by_name: HashMap<String, Foreign>,
+}
+
+#[test]
+fn uncategorized_elements() {
+    assert_eq!(
+        Imported::fields_by_category(Category::COLLECTION),
+        vec!["items", "by_name"]
+    );
+    assert_eq!(Imported::field_category("maybe"), Some(Category::OPTIONAL));
+    assert_eq!(
+        Imported::fields_by_inner_category(Category::UNKNOWN),
+        vec!["items", "maybe", "by_name"]
+    );
+    let by_name = Imported::find_by_name("by_name").unwrap();
+    assert_eq!(by_name.key_category(), Some(Category::TEXT));
+}
+
+#[derive(FieldKinds)]
+struct Bag<T> {
+    items: Vec<T>,
+}
+
+#[test]
+fn unbounded_generic_elements() {
+    let items = Bag::<Foreign>::find_by_name("items").unwrap();
+    assert_eq!(items.category, Category::COLLECTION);
+    assert_eq!(items.inner_category(), None);
+}
+
+#[test]
+fn fields_by_inner_category() {
+    assert_eq!(
+        Profile::fields_by_inner_category(Category::TEXT),
+        vec!["nickname", "emails"]
+    );
+    assert_eq!(
+        Profile::fields_by_inner_category(Category::NUMERIC),
+        vec!["age", "scores"]
+    );
+}
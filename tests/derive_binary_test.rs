@@ -0,0 +1,68 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Categorized, Category, FieldKinds, FieldKindsExt, TypeCategory,
+};
+
+#[derive(FieldKinds)]
+pub struct Attachment {
+    pub name: String,
+    #[field_kinds(binary)]
+    pub content: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub thumbnail: Vec<u8>,
+    #[field_kinds(binary)]
+    pub digest: [u8; 32],
+    pub compressed: Box<[u8]>,
+    pub flags: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    #[field_kinds(category = "collection")]
+    pub raw: Vec<u8>,
+}
+
+#[test]
+fn box_u8_slice_is_binary() {
+    assert_eq!(
+        <<Box<[u8]> as Categorized>::Category as TypeCategory>::CATEGORY,
+        Category::BINARY
+    );
+}
+
+#[test]
+fn binary_fields() {
+    assert_eq!(
+        Attachment::fields_by_category(Category::BINARY),
+        vec!["content", "thumbnail", "digest", "compressed"]
+    );
+}
+
+#[test]
+fn byte_vectors_stay_collections_without_opt_in() {
+    assert_eq!(Attachment::field_category("flags"), Some(Category::COLLECTION));
+}
+
+#[test]
+fn explicit_category_wins_over_serde_bytes() {
+    assert_eq!(Attachment::field_category("raw"), Some(Category::COLLECTION));
+}
+
+#[cfg(feature = "bytes")]
+mod bytes_types {
+    use bytes::{Bytes, BytesMut};
+    use field_kinds::{Category, FieldKinds, FieldKindsExt};
+
+    #[derive(FieldKinds)]
+    struct Frame {
+        header: Bytes,
+        body: Option<BytesMut>,
+    }
+
+    #[test]
+    fn bytes_types_are_binary() {
+        assert_eq!(Frame::fields_by_category(Category::BINARY), vec!["header"]);
+        assert_eq!(
+            Frame::fields_by_inner_category(Category::BINARY),
+            vec!["body"]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Category, FieldInfo, FieldKinds, FieldKindsExt, TypeCategory, VisitFields,
+};
+use std::net::{IpAddr, Ipv4Addr};
+
+mod markers {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Network;
+
+    impl field_kinds::TypeCategory for Network {
+        const NAME: &'static str = "network";
+    }
+}
+
+/// Stands in for a third-party type that cannot implement `Categorized`.
+pub struct Foreign(Ipv4Addr);
+
+#[derive(FieldKinds)]
+pub struct Host {
+    #[field_kinds(category = markers::Network)]
+    pub address: IpAddr,
+    #[field_kinds(category = "text")]
+    pub foreign: Foreign,
+    #[field_kinds(category = "numeric")]
+    pub port: Option<u16>,
+    pub name: String,
+}
+
+#[test]
+fn builtin_category_override() {
+    assert_eq!(Host::field_category("foreign"), Some(Category::TEXT));
+    assert_eq!(Host::field_category("port"), Some(Category::NUMERIC));
+    assert_eq!(Host::field_category("name"), Some(Category::TEXT));
+}
+
+#[test]
+fn custom_category_override() {
+    let network = markers::Network::CATEGORY;
+    assert_eq!(Host::field_category("address"), Some(network));
+    assert_eq!(Host::fields_by_category(network), vec!["address"]);
+}
+
+#[test]
+fn overridden_fields_have_no_nested_categories() {
+    let port = Host::find_by_name("port").unwrap();
+    assert_eq!(port.inner_category(), None);
+    assert_eq!(port.category_chain().collect::<Vec<_>>(), [Category::NUMERIC]);
+}
+
+#[test]
+fn override_applies_to_field_info() {
+    assert_eq!(<host_fields::Address as FieldInfo>::CATEGORY_NAME, "network");
+    assert_eq!(Host::FIELD_COUNT, 4);
+}
+
+#[derive(FieldKinds)]
+pub enum Event {
+    Connected(#[field_kinds(category = "text")] Foreign),
+}
+
+#[test]
+fn override_in_variant_fields() {
+    use field_kinds::VisitVariants;
+    assert_eq!(Event::VARIANTS[0].fields[0].category, Category::TEXT);
+}
@@ -0,0 +1,121 @@
+use field_kinds::{Categorized, FieldChange, FieldDiff, FieldKinds, REDACTED};
+
+#[derive(FieldKinds, Clone)]
+#[field_kinds(diff)]
+pub struct Credentials {
+    pub user: String,
+    #[field_tags("sensitive")]
+    pub password: String,
+}
+
+#[derive(FieldKinds, Clone)]
+#[field_kinds(diff)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    pub account_id: u64,
+    pub display_name: String,
+    pub email: Option<String>,
+    pub roles: Vec<String>,
+    #[field_kinds(skip)]
+    pub last_seen: u64,
+    #[serde(flatten)]
+    pub credentials: Credentials,
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(diff)]
+pub struct Pair<T: Categorized> {
+    pub left: T,
+    pub right: T,
+}
+
+fn account() -> Account {
+    Account {
+        account_id: 1,
+        display_name: "Ann".into(),
+        email: None,
+        roles: vec!["reader".into()],
+        last_seen: 100,
+        credentials: Credentials {
+            user: "ann".into(),
+            password: "hunter2".into(),
+        },
+    }
+}
+
+fn names(changes: &[FieldChange]) -> Vec<&'static str> {
+    changes.iter().map(|c| c.meta.name).collect()
+}
+
+#[test]
+fn identical_values_have_no_changes() {
+    assert!(account().diff(&account()).is_empty());
+}
+
+#[test]
+fn reports_changed_fields_in_order() {
+    let old = account();
+    let mut new = account();
+    new.roles.push("writer".into());
+    new.email = Some("ann@example.com".into());
+
+    let changes = FieldDiff::diff(&old, &new);
+    assert_eq!(names(&changes), vec!["email", "roles"]);
+    assert_eq!(changes[0].meta.serialized_name, "email");
+    assert_eq!(changes[0].before, "None");
+    assert_eq!(changes[0].after, r#"Some("ann@example.com")"#);
+    assert_eq!(changes[1].before, r#"["reader"]"#);
+    assert_eq!(changes[1].after, r#"["reader", "writer"]"#);
+    assert!(!changes[1].is_redacted());
+}
+
+#[test]
+fn skipped_fields_are_ignored() {
+    let old = account();
+    let mut new = account();
+    new.last_seen = 200;
+    assert!(old.diff(&new).is_empty());
+}
+
+#[test]
+fn flattened_fields_use_outer_meta() {
+    let old = account();
+    let mut new = account();
+    new.credentials.user = "anna".into();
+
+    let changes = old.diff(&new);
+    assert_eq!(names(&changes), vec!["user"]);
+    assert_eq!(changes[0].meta.flattened_from, Some("credentials"));
+    assert_eq!(changes[0].before, r#""ann""#);
+}
+
+#[test]
+fn sensitive_fields_are_redacted() {
+    let old = account();
+    let mut new = account();
+    new.credentials.password = "correct horse".into();
+    new.account_id = 2;
+
+    let changes = old.diff(&new);
+    assert_eq!(names(&changes), vec!["account_id", "password"]);
+    assert_eq!(changes[0].before, "1");
+    assert_eq!(changes[0].after, "2");
+    assert!(changes[1].is_redacted());
+    assert_eq!(changes[1].before, REDACTED);
+    assert_eq!(changes[1].after, REDACTED);
+}
+
+#[test]
+fn generic_struct() {
+    let old = Pair {
+        left: 1.5,
+        right: 2.0,
+    };
+    let new = Pair {
+        left: 1.5,
+        right: 3.0,
+    };
+    let changes = old.diff(&new);
+    assert_eq!(names(&changes), vec!["right"]);
+    assert_eq!(changes[0].after, "3.0");
+}
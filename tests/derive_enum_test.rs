@@ -0,0 +1,180 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Categorized, Category, FieldKinds, VariantKind, VariantKindsExt,
+    VisitVariants,
+};
+
+#[derive(FieldKinds)]
+enum Event {
+    Created { id: u64, at: String },
+    Moved(u32, u32),
+    Deleted,
+}
+
+#[test]
+fn variant_count() {
+    assert_eq!(Event::VARIANT_COUNT, 3);
+}
+
+#[test]
+fn variant_names() {
+    assert_eq!(Event::variant_names(), vec!["Created", "Moved", "Deleted"]);
+    assert_eq!(
+        Event::serialized_variant_names(),
+        vec!["Created", "Moved", "Deleted"]
+    );
+}
+
+#[test]
+fn variant_kinds() {
+    let kinds: Vec<_> = Event::VARIANTS.iter().map(|v| v.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![VariantKind::Struct, VariantKind::Tuple, VariantKind::Unit]
+    );
+}
+
+#[test]
+fn struct_variant_fields() {
+    let created = Event::find_variant("Created").unwrap();
+    assert_eq!(created.fields.len(), 2);
+    assert_eq!(created.fields[0].name, "id");
+    assert_eq!(created.fields[0].category, Category::NUMERIC);
+    assert_eq!(created.fields[1].name, "at");
+    assert_eq!(created.fields[1].category, Category::TEXT);
+    assert_eq!(created.find_field("at").unwrap().serialized_name, "at");
+}
+
+#[test]
+fn tuple_variant_fields_are_positional() {
+    let moved = Event::find_variant("Moved").unwrap();
+    let names: Vec<_> = moved.fields.iter().map(|f| f.name).collect();
+    assert_eq!(names, vec!["0", "1"]);
+    assert!(moved.fields.iter().all(|f| f.category == Category::NUMERIC));
+}
+
+#[test]
+fn unit_variant_has_no_fields() {
+    let deleted = Event::find_variant("Deleted").unwrap();
+    assert!(deleted.fields.is_empty());
+}
+
+#[test]
+fn has_variant() {
+    assert!(Event::has_variant("Moved"));
+    assert!(!Event::has_variant("Archived"));
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+enum Renamed {
+    AccountOpened {
+        user_id: u64,
+        #[serde(rename = "ts")]
+        created_at: u64,
+    },
+    #[serde(rename = "gone")]
+    UserDeleted,
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    HomeMoved { new_home: String },
+}
+
+#[test]
+fn rename_all_applies_to_variants() {
+    assert_eq!(
+        Renamed::serialized_variant_names(),
+        vec!["account_opened", "gone", "home_moved"]
+    );
+    assert_eq!(
+        Renamed::find_variant_by_serialized_name("gone")
+            .unwrap()
+            .name,
+        "UserDeleted"
+    );
+}
+
+#[test]
+fn rename_all_fields_applies_to_variant_fields() {
+    let created = Renamed::find_variant("AccountOpened").unwrap();
+    let names: Vec<_> =
+        created.fields.iter().map(|f| f.serialized_name).collect();
+    assert_eq!(names, vec!["userId", "ts"]);
+}
+
+#[test]
+fn variant_rename_all_overrides_rename_all_fields() {
+    let moved = Renamed::find_variant("HomeMoved").unwrap();
+    assert_eq!(moved.fields[0].serialized_name, "NEW_HOME");
+}
+
+#[derive(FieldKinds)]
+enum WithSkips {
+    Visible {
+        kept: bool,
+        #[field_kinds(skip)]
+        dropped: bool,
+    },
+    #[field_kinds(skip)]
+    Hidden,
+}
+
+#[test]
+fn skip_variants_and_fields() {
+    assert_eq!(WithSkips::variant_names(), vec!["Visible"]);
+    assert_eq!(WithSkips::VARIANTS[0].fields.len(), 1);
+    assert_eq!(WithSkips::VARIANTS[0].fields[0].name, "kept");
+}
+
+#[derive(FieldKinds)]
+enum Either<L: Categorized, R: Categorized> {
+    Left(L),
+    Right(R),
+}
+
+#[test]
+fn generic_enum() {
+    assert_eq!(
+        Either::<i32, String>::VARIANTS[0].fields[0].category,
+        Category::NUMERIC
+    );
+    assert_eq!(
+        Either::<i32, String>::VARIANTS[1].fields[0].category,
+        Category::TEXT
+    );
+}
+
+#[derive(FieldKinds)]
+enum Empty {}
+
+#[test]
+fn empty_enum() {
+    assert_eq!(Empty::VARIANT_COUNT, 0);
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all(serialize = "lowercase", deserialize = "snake_case"))]
+enum Status {
+    InProgress,
+    #[serde(rename(deserialize = "done"))]
+    Finished,
+}
+
+#[test]
+fn directional_variant_names() {
+    assert_eq!(
+        Status::serialized_variant_names(),
+        vec!["inprogress", "finished"]
+    );
+    let names: Vec<_> = Status::VARIANTS
+        .iter()
+        .map(|v| v.deserialize_name)
+        .collect();
+    assert_eq!(names, vec!["in_progress", "done"]);
+    assert_eq!(
+        Status::find_variant_by_deserialize_name("done")
+            .unwrap()
+            .name,
+        "Finished"
+    );
+}
@@ -0,0 +1,347 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use field_kinds::{
+    EnvError, EnvVarError, FieldKinds, FieldKindsExt, FieldSetError, FromEnv,
+    VisitFields, env_var_name,
+};
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+pub struct Retry {
+    pub max_attempts: u8,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceConfig {
+    pub pool_size: u32,
+    #[serde(rename = "db-host")]
+    pub host: String,
+    pub verbose: bool,
+    pub read_only: Option<bool>,
+    pub replica: Option<String>,
+    pub ports: Vec<u16>,
+    pub labels: HashMap<String, String>,
+    #[serde(flatten)]
+    pub retry: Retry,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+pub struct Db {
+    pub host: String,
+    pub port: u16,
+    pub replicas: Vec<String>,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+pub struct Plain {
+    pub level: u8,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+#[serde(rename_all = "camelCase")]
+pub struct AppConfig {
+    pub app_name: String,
+    pub primary_db: Db,
+    pub cache: Option<Db>,
+    #[serde(flatten)]
+    pub retry: Retry,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+pub struct WithPlain {
+    pub logging: Plain,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+pub struct Worker {
+    pub threads: u8,
+    #[serde(skip)]
+    pub counter: u64,
+    #[serde(skip_deserializing)]
+    pub started: bool,
+}
+
+#[derive(FieldKinds, Default, Debug)]
+#[field_kinds(env)]
+pub struct Client {
+    pub url: String,
+    pub timeout: Duration,
+}
+
+fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let map: HashMap<String, String> = pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+        .collect();
+    move |name| map.get(name).cloned()
+}
+
+#[test]
+fn var_names() {
+    let names: Vec<_> = ServiceConfig::FIELDS
+        .iter()
+        .map(|f| env_var_name("APP", f))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "APP_POOL_SIZE",
+            "APP_DB_HOST",
+            "APP_VERBOSE",
+            "APP_READ_ONLY",
+            "APP_REPLICA",
+            "APP_PORTS",
+            "APP_LABELS",
+            "APP_MAX_ATTEMPTS",
+        ]
+    );
+    let pool = ServiceConfig::find_by_name("pool_size").unwrap();
+    assert_eq!(env_var_name("APP_", pool), "APP_POOL_SIZE");
+    assert_eq!(env_var_name("", pool), "POOL_SIZE");
+}
+
+#[test]
+fn from_env_with_all_vars() {
+    let config = ServiceConfig::from_env_with(
+        "APP",
+        vars(&[
+            ("APP_POOL_SIZE", "20"),
+            ("APP_DB_HOST", "db.internal"),
+            ("APP_VERBOSE", "on"),
+            ("APP_READ_ONLY", "0"),
+            ("APP_MAX_ATTEMPTS", "3"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(config.pool_size, 20);
+    assert_eq!(config.host, "db.internal");
+    assert!(config.verbose);
+    assert_eq!(config.read_only, Some(false));
+    assert_eq!(config.replica, None);
+    assert_eq!(config.retry.max_attempts, 3);
+}
+
+#[test]
+fn reports_all_errors_at_once() {
+    let err = ServiceConfig::from_env_with(
+        "APP",
+        vars(&[("APP_POOL_SIZE", "many"), ("APP_VERBOSE", "maybe")]),
+    )
+    .unwrap_err();
+    assert_eq!(err.errors.len(), 4);
+    assert!(matches!(
+        &err.errors[0],
+        EnvVarError::Invalid { var, error: FieldSetError::Parse { field: "pool_size", .. } }
+            if var == "APP_POOL_SIZE"
+    ));
+    assert_eq!(
+        err.errors[1],
+        EnvVarError::Missing {
+            var: "APP_DB_HOST".into()
+        }
+    );
+    assert!(
+        matches!(&err.errors[2], EnvVarError::Invalid { var, .. } if var == "APP_VERBOSE")
+    );
+    assert_eq!(
+        err.errors[3],
+        EnvVarError::Missing {
+            var: "APP_MAX_ATTEMPTS".into()
+        }
+    );
+    assert!(
+        err.to_string()
+            .starts_with("invalid environment: APP_POOL_SIZE: ")
+    );
+}
+
+#[test]
+fn apply_env_overrides_set_vars_only() {
+    let mut config = ServiceConfig {
+        pool_size: 5,
+        host: "localhost".into(),
+        ..ServiceConfig::default()
+    };
+    config
+        .apply_env_with(
+            "SVC",
+            vars(&[("SVC_POOL_SIZE", "8"), ("SVC_REPLICA", "r1")]),
+        )
+        .unwrap();
+    assert_eq!(config.pool_size, 8);
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.replica.as_deref(), Some("r1"));
+}
+
+#[test]
+fn apply_env_keeps_valid_values_on_error() {
+    let mut config = ServiceConfig::default();
+    let err: EnvError = config
+        .apply_env_with(
+            "SVC",
+            vars(&[("SVC_POOL_SIZE", "8"), ("SVC_MAX_ATTEMPTS", "300")]),
+        )
+        .unwrap_err();
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(config.pool_size, 8);
+}
+
+#[test]
+fn reads_process_environment() {
+    // SAFETY: the variable name is unique to this test.
+    unsafe { std::env::set_var("FIELD_KINDS_ENV_TEST_MAX_ATTEMPTS", "7") };
+    let retry = Retry::from_env("FIELD_KINDS_ENV_TEST").unwrap();
+    assert_eq!(retry.max_attempts, 7);
+}
+
+#[test]
+fn lists_are_comma_separated() {
+    let config = ServiceConfig::from_env_with(
+        "APP",
+        vars(&[
+            ("APP_POOL_SIZE", "1"),
+            ("APP_DB_HOST", "h"),
+            ("APP_VERBOSE", "no"),
+            ("APP_MAX_ATTEMPTS", "1"),
+            ("APP_PORTS", "80, 443,,8080"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(config.ports, vec![80, 443, 8080]);
+    assert!(config.labels.is_empty());
+}
+
+#[test]
+fn invalid_list_item_and_unparseable_collection() {
+    let mut config = ServiceConfig::default();
+    let err = config
+        .apply_env_with(
+            "APP",
+            vars(&[("APP_PORTS", "80,http"), ("APP_LABELS", "a=b")]),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        &err.errors[0],
+        EnvVarError::Invalid { var, error: FieldSetError::Parse { field: "ports", value, .. } }
+            if var == "APP_PORTS" && value == "http"
+    ));
+    assert!(matches!(
+        &err.errors[1],
+        EnvVarError::Invalid {
+            error: FieldSetError::Unsupported { field: "labels" },
+            ..
+        }
+    ));
+    assert_eq!(err.errors.len(), 2);
+}
+
+#[test]
+fn nested_records_read_prefixed_fields() {
+    let config = AppConfig::from_env_with(
+        "APP",
+        vars(&[
+            ("APP_APP_NAME", "api"),
+            ("APP_PRIMARY_DB_HOST", "db.internal"),
+            ("APP_PRIMARY_DB_PORT", "5432"),
+            ("APP_PRIMARY_DB_REPLICAS", "r1,r2"),
+            ("APP_MAX_ATTEMPTS", "2"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(config.app_name, "api");
+    assert_eq!(config.primary_db.host, "db.internal");
+    assert_eq!(config.primary_db.port, 5432);
+    assert_eq!(config.primary_db.replicas, vec!["r1", "r2"]);
+    assert!(config.cache.is_none());
+    assert_eq!(config.retry.max_attempts, 2);
+}
+
+#[test]
+fn nested_records_report_their_own_vars() {
+    let err = AppConfig::from_env_with(
+        "APP",
+        vars(&[
+            ("APP_APP_NAME", "api"),
+            ("APP_PRIMARY_DB_PORT", "db"),
+            ("APP_MAX_ATTEMPTS", "2"),
+        ]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.errors[0],
+        EnvVarError::Missing {
+            var: "APP_PRIMARY_DB_HOST".into()
+        }
+    );
+    assert!(matches!(
+        &err.errors[1],
+        EnvVarError::Invalid { var, error: FieldSetError::Parse { field: "port", .. } }
+            if var == "APP_PRIMARY_DB_PORT"
+    ));
+    assert_eq!(err.errors.len(), 2);
+}
+
+#[test]
+fn nested_record_without_env_is_reported() {
+    let mut config = WithPlain::default();
+    let err = config
+        .apply_env_with("APP", vars(&[("APP_LOGGING_LEVEL", "3")]))
+        .unwrap_err();
+    assert!(matches!(
+        &err.errors[0],
+        EnvVarError::Invalid { var, error: FieldSetError::UnknownField { .. } }
+            if var == "APP_LOGGING_LEVEL"
+    ));
+}
+
+#[test]
+fn serde_skipped_fields_are_not_read() {
+    let worker = Worker::from_env_with(
+        "APP",
+        vars(&[
+            ("APP_THREADS", "4"),
+            ("APP_COUNTER", "9"),
+            ("APP_STARTED", "true"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(worker.threads, 4);
+    assert_eq!(worker.counter, 0);
+    assert!(!worker.started);
+    assert!(
+        Worker::from_env_with("APP", vars(&[("APP_THREADS", "4")])).is_ok()
+    );
+}
+
+#[test]
+fn fields_without_from_str_are_not_required() {
+    assert!(!Client::find_by_name("timeout").unwrap().settable_from_str);
+    assert!(Client::find_by_name("url").unwrap().settable_from_str);
+
+    let client =
+        Client::from_env_with("APP", vars(&[("APP_URL", "http://db")]))
+            .unwrap();
+    assert_eq!(client.timeout, Duration::default());
+
+    let err = Client::from_env_with(
+        "APP",
+        vars(&[("APP_URL", "http://db"), ("APP_TIMEOUT", "30")]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.errors,
+        vec![EnvVarError::Invalid {
+            var: "APP_TIMEOUT".to_owned(),
+            error: FieldSetError::Unsupported { field: "timeout" },
+        }]
+    );
+}
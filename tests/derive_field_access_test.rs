@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+
+use field_kinds::{Categorized, FieldAccess, FieldKinds};
+
+#[derive(FieldKinds)]
+pub struct Pagination {
+    pub page: u32,
+    pub per_page: u32,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+pub struct Listing {
+    pub user_id: u64,
+    pub title: Option<String>,
+    #[field_kinds(skip)]
+    pub cache: Vec<u8>,
+    #[serde(flatten)]
+    pub pagination: Pagination,
+}
+
+fn listing() -> Listing {
+    Listing {
+        user_id: 7,
+        title: Some("Hello".into()),
+        cache: vec![1, 2],
+        pagination: Pagination {
+            page: 2,
+            per_page: 50,
+        },
+    }
+}
+
+#[test]
+fn get_by_name() {
+    let listing = listing();
+    assert_eq!(listing.get_as::<u64>("user_id"), Some(&7));
+    assert_eq!(
+        listing.get_as::<Option<String>>("title"),
+        Some(&Some("Hello".to_owned()))
+    );
+}
+
+#[test]
+fn wrong_type_or_unknown_name() {
+    let listing = listing();
+    assert!(listing.get("user_id").is_some());
+    assert_eq!(listing.get_as::<u32>("user_id"), None);
+    assert!(listing.get("userId").is_none());
+    assert!(listing.get("missing").is_none());
+}
+
+#[test]
+fn skipped_fields_are_not_accessible() {
+    assert!(listing().get("cache").is_none());
+}
+
+#[test]
+fn flattened_fields() {
+    let mut listing = listing();
+    assert_eq!(listing.get_as::<u32>("per_page"), Some(&50));
+    assert!(listing.get("pagination").is_none());
+
+    *listing
+        .get_mut("page")
+        .and_then(|v| v.downcast_mut::<u32>())
+        .unwrap() = 3;
+    assert_eq!(listing.pagination.page, 3);
+}
+
+#[test]
+fn get_mut() {
+    let mut listing = listing();
+    let id = listing.get_mut("user_id").unwrap();
+    *id.downcast_mut::<u64>().unwrap() += 1;
+    assert_eq!(listing.user_id, 8);
+    assert!(listing.get_mut("missing").is_none());
+}
+
+#[derive(FieldKinds)]
+pub struct Point(pub f64, pub f64);
+
+#[test]
+fn tuple_structs() {
+    let point = Point(1.0, 2.0);
+    assert_eq!(point.get_as::<f64>("1"), Some(&2.0));
+}
+
+#[derive(FieldKinds)]
+pub struct Wrapper<T: Categorized> {
+    pub value: T,
+}
+
+#[test]
+fn generic_structs() {
+    let wrapper = Wrapper { value: 5_i32 };
+    assert_eq!(wrapper.get_as::<i32>("value"), Some(&5));
+}
+
+#[test]
+fn object_safe() {
+    let listing = listing();
+    let access: &dyn FieldAccess = &listing;
+    assert!(access.get("title").is_some());
+}
@@ -0,0 +1,174 @@
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+
+use field_kinds::{
+    Category, FieldAccess, FieldKinds, FieldKindsExt, FieldSetError,
+    SetFieldFromStr, VisitFields, VisitVariants,
+};
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+struct Pagination {
+    page_size: u32,
+    page_token: Option<String>,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+struct Audit {
+    created_by: String,
+    #[serde(flatten)]
+    paging: Pagination,
+}
+
+#[derive(FieldKinds)]
+struct Request {
+    query: String,
+    #[serde(flatten)]
+    audit: Audit,
+    #[field_tags("debug")]
+    verbose: bool,
+}
+
+#[test]
+fn flattened_fields_are_spliced_in_order() {
+    assert_eq!(
+        Request::field_names(),
+        vec!["query", "created_by", "page_size", "page_token", "verbose"]
+    );
+    assert_eq!(Request::FIELD_COUNT, 5);
+}
+
+#[test]
+fn serialized_names_match_serde_output() {
+    assert_eq!(
+        Request::serialized_names(),
+        vec!["query", "createdBy", "pageSize", "pageToken", "verbose"]
+    );
+}
+
+#[test]
+fn flattened_entries_record_outer_field() {
+    let sources: Vec<_> =
+        Request::FIELDS.iter().map(|f| f.flattened_from).collect();
+    assert_eq!(
+        sources,
+        vec![None, Some("audit"), Some("audit"), Some("audit"), None]
+    );
+    assert!(Request::find_by_name("page_size").unwrap().is_flattened());
+    assert!(!Request::find_by_name("verbose").unwrap().is_flattened());
+}
+
+#[test]
+fn nested_flatten_is_recorded_relative_to_each_struct() {
+    let page_size = Audit::find_by_name("page_size").unwrap();
+    assert_eq!(page_size.flattened_from, Some("paging"));
+    assert_eq!(page_size.index, 0);
+}
+
+#[test]
+fn flattened_metadata_is_preserved() {
+    assert_eq!(Request::field_category("page_token"), Some(Category::OPTIONAL));
+    assert_eq!(Request::fields_by_tag("debug"), vec!["verbose"]);
+}
+
+#[derive(FieldKinds)]
+struct OnlyFlattened {
+    #[serde(flatten)]
+    inner: Pagination,
+}
+
+#[test]
+fn find_by_index_skips_spliced_entries() {
+    assert_eq!(Request::find_by_index(0).unwrap().name, "query");
+    assert!(Request::find_by_index(1).is_none());
+    assert_eq!(Request::find_by_index(2).unwrap().name, "verbose");
+    assert!(OnlyFlattened::find_by_index(0).is_none());
+}
+
+#[test]
+fn struct_with_only_flattened_field() {
+    assert_eq!(OnlyFlattened::field_names(), vec!["page_size", "page_token"]);
+}
+
+#[derive(FieldKinds)]
+struct SkippedFlatten {
+    id: u64,
+    #[field_kinds(skip)]
+    #[serde(flatten)]
+    extra: Pagination,
+}
+
+#[test]
+fn skip_takes_precedence_over_flatten() {
+    assert_eq!(SkippedFlatten::field_names(), vec!["id"]);
+}
+
+#[derive(FieldKinds)]
+enum Command {
+    List {
+        filter: String,
+        #[serde(flatten)]
+        paging: Pagination,
+    },
+}
+
+#[test]
+fn flatten_in_struct_variant() {
+    let names: Vec<_> =
+        Command::VARIANTS[0].fields.iter().map(|f| f.name).collect();
+    assert_eq!(names, vec!["filter", "page_size", "page_token"]);
+}
+
+#[derive(FieldKinds, Default)]
+struct Event {
+    kind: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+#[derive(FieldKinds)]
+struct Search {
+    #[serde(flatten)]
+    paging: Option<Pagination>,
+    #[serde(flatten)]
+    filters: BTreeMap<String, u32>,
+    #[serde(flatten)]
+    audit: Audit,
+}
+
+#[test]
+fn flattened_map_is_kept_as_a_field() {
+    assert_eq!(Event::field_names(), vec!["kind", "extra"]);
+    let extra = Event::find_by_name("extra").unwrap();
+    assert_eq!(extra.category, Category::COLLECTION);
+    assert_eq!(extra.flattened_from, Some("extra"));
+    assert!(!Event::find_by_name("kind").unwrap().is_flattened());
+    assert_eq!(Event::find_by_index(1).unwrap().name, "extra");
+}
+
+#[test]
+fn flattened_map_value_access() {
+    let mut event = Event::default();
+    event.extra.insert("source".into(), "cli".into());
+    let extra = event.get_as::<HashMap<String, String>>("extra").unwrap();
+    assert_eq!(extra["source"], "cli");
+    assert_eq!(
+        event.set_field_from_str("extra", "x"),
+        Err(FieldSetError::Unsupported { field: "extra" })
+    );
+}
+
+#[test]
+fn flattened_option_is_kept_as_a_field() {
+    assert_eq!(
+        Search::field_names(),
+        vec!["paging", "filters", "created_by", "page_size", "page_token"]
+    );
+    let paging = Search::find_by_name("paging").unwrap();
+    assert_eq!(paging.category, Category::OPTIONAL);
+    assert_eq!(paging.flattened_from, Some("paging"));
+    let page_size = Search::find_by_name("page_size").unwrap();
+    assert_eq!(page_size.flattened_from, Some("audit"));
+}
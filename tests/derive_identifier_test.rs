@@ -0,0 +1,105 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Category, FieldKinds, FieldKindsExt, NumericClass, NumericKind,
+};
+
+#[derive(FieldKinds)]
+pub struct Order {
+    #[field_kinds(identifier)]
+    pub id: u64,
+    #[field_kinds(identifier)]
+    #[field_tags("foreign_key")]
+    #[serde(rename = "customerId")]
+    pub customer: Option<u64>,
+    #[field_kinds(category = "identifier")]
+    pub sku: String,
+    pub quantity: u32,
+}
+
+#[test]
+fn identifier_attribute_sets_category() {
+    assert_eq!(Order::field_category("id"), Some(Category::IDENTIFIER));
+    assert_eq!(Order::field_category("quantity"), Some(Category::NUMERIC));
+}
+
+#[test]
+fn identifier_keeps_other_metadata() {
+    let customer = Order::find_by_name("customer").unwrap();
+    assert_eq!(customer.category, Category::IDENTIFIER);
+    assert_eq!(customer.serialized_name, "customerId");
+    assert!(customer.has_tag("foreign_key"));
+}
+
+#[test]
+fn identifier_keeps_numeric_metadata() {
+    let id = Order::find_by_name("id").unwrap();
+    assert_eq!(id.numeric_kind(), Some(NumericKind::unsigned(64)));
+    assert_eq!(id.numeric_class, Some(NumericClass::Integer));
+    let customer = Order::find_by_name("customer").unwrap();
+    assert_eq!(customer.inner_category(), Some(Category::NUMERIC));
+    assert_eq!(
+        customer.inner.unwrap().numeric_kind,
+        Some(NumericKind::unsigned(64))
+    );
+    let sku = Order::find_by_name("sku").unwrap();
+    assert_eq!(sku.numeric_kind(), None);
+}
+
+/// An id type without a `Categorized` impl.
+pub struct ExternalId(u128);
+
+#[derive(FieldKinds)]
+pub struct Import {
+    #[field_kinds(identifier)]
+    pub source: ExternalId,
+}
+
+#[test]
+fn identifier_on_uncategorized_type() {
+    let source = Import::find_by_name("source").unwrap();
+    assert_eq!(source.category, Category::IDENTIFIER);
+    assert_eq!(source.numeric_kind(), None);
+}
+
+#[test]
+fn identifier_fields() {
+    assert_eq!(Order::identifier_fields(), vec!["id", "customer", "sku"]);
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_types {
+    use field_kinds::{
+        Categorized, Category, FieldKinds, FieldKindsExt, NestedCategory,
+        TypeCategory,
+    };
+    use uuid::{NonNilUuid, Uuid};
+
+    #[test]
+    fn uuid_is_identifier() {
+        assert_eq!(
+            <<Uuid as Categorized>::Category as TypeCategory>::CATEGORY,
+            Category::IDENTIFIER
+        );
+        assert_eq!(
+            NestedCategory::of::<NonNilUuid>().category,
+            Category::IDENTIFIER
+        );
+    }
+
+    #[derive(FieldKinds)]
+    struct Session {
+        id: Uuid,
+        parent: Option<Uuid>,
+        token: String,
+    }
+
+    #[test]
+    fn derive_with_uuid_fields() {
+        assert_eq!(Session::identifier_fields(), vec!["id"]);
+        assert_eq!(
+            Session::fields_by_inner_category(Category::IDENTIFIER),
+            vec!["parent"]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Category, FieldKinds, FieldKindsExt, NumericClass, NumericKind,
+};
+
+#[derive(FieldKinds)]
+pub struct Measurement {
+    pub id: u64,
+    pub value: f64,
+    pub count: Option<u32>,
+    pub label: String,
+    #[field_kinds(category = "numeric")]
+    pub raw: String,
+}
+
+#[test]
+fn numeric_class_on_field_meta() {
+    let id = Measurement::find_by_name("id").unwrap();
+    assert_eq!(id.numeric_class, Some(NumericClass::Integer));
+    let value = Measurement::find_by_name("value").unwrap();
+    assert_eq!(value.numeric_class, Some(NumericClass::Float));
+    let label = Measurement::find_by_name("label").unwrap();
+    assert_eq!(label.numeric_class, None);
+}
+
+#[test]
+fn overridden_numeric_fields_have_no_class() {
+    let raw = Measurement::find_by_name("raw").unwrap();
+    assert_eq!(raw.category, Category::NUMERIC);
+    assert_eq!(raw.numeric_class, None);
+}
+
+#[test]
+fn fields_by_numeric_class() {
+    assert_eq!(
+        Measurement::fields_by_numeric_class(NumericClass::Integer),
+        vec!["id"]
+    );
+    assert_eq!(
+        Measurement::fields_by_numeric_class(NumericClass::Float),
+        vec!["value"]
+    );
+    let count = Measurement::find_by_name("count").unwrap();
+    assert_eq!(count.inner.unwrap().numeric_class, Some(NumericClass::Integer));
+}
+
+#[derive(FieldKinds)]
+pub struct Columns {
+    pub small: i16,
+    pub big: u64,
+    pub ratio: f32,
+    pub total: f64,
+    pub maybe: Option<i8>,
+    pub name: String,
+}
+
+#[test]
+fn numeric_kind_on_field_meta() {
+    let small = Columns::find_by_name("small").unwrap();
+    assert_eq!(small.numeric_kind(), Some(NumericKind::signed(16)));
+    assert_eq!(small.numeric_kind, small.numeric_kind());
+    let name = Columns::find_by_name("name").unwrap();
+    assert_eq!(name.numeric_kind(), None);
+}
+
+#[test]
+fn fields_matching_numeric_kind() {
+    assert_eq!(
+        Columns::fields_matching(|m| m.numeric_kind().is_some_and(|k| k.float)),
+        vec!["ratio", "total"]
+    );
+    assert_eq!(
+        Columns::fields_matching(|m| m
+            .numeric_kind()
+            .is_some_and(|k| !k.signed)),
+        vec!["big"]
+    );
+    assert_eq!(
+        Columns::fields_matching(|m| m
+            .numeric_kind()
+            .is_some_and(|k| k.bits == 64)),
+        vec!["big", "total"]
+    );
+}
+
+#[test]
+fn fields_matching_other_predicates() {
+    assert_eq!(
+        Columns::fields_matching(|m| m.category == Category::TEXT),
+        vec!["name"]
+    );
+    assert!(Columns::fields_matching(|_| false).is_empty());
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal_types {
+    use field_kinds::{Category, FieldKinds, FieldKindsExt, NumericClass};
+    use rust_decimal::Decimal;
+
+    #[derive(FieldKinds)]
+    struct Invoice {
+        total: Decimal,
+        discount: Option<Decimal>,
+        lines: u32,
+    }
+
+    #[test]
+    fn decimal_is_numeric() {
+        assert_eq!(
+            Invoice::fields_by_category(Category::NUMERIC),
+            vec!["total", "lines"]
+        );
+        assert_eq!(
+            Invoice::fields_by_numeric_class(NumericClass::Decimal),
+            vec!["total"]
+        );
+        let total = Invoice::find_by_name("total").unwrap();
+        assert_eq!(total.numeric_kind(), None);
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_types {
+    use bigdecimal::BigDecimal;
+    use field_kinds::{Category, FieldKinds, FieldKindsExt, NumericClass};
+
+    #[derive(FieldKinds)]
+    struct Ledger {
+        balance: BigDecimal,
+    }
+
+    #[test]
+    fn big_decimal_is_numeric() {
+        let balance = Ledger::find_by_name("balance").unwrap();
+        assert_eq!(balance.category, Category::NUMERIC);
+        assert_eq!(balance.numeric_class, Some(NumericClass::Decimal));
+    }
+}
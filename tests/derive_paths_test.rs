@@ -0,0 +1,161 @@
+#![allow(dead_code)]
+
+use field_kinds::{Category, FieldKinds, FieldKindsExt};
+
+#[derive(FieldKinds)]
+pub struct Address {
+    pub city: String,
+    #[serde(rename = "postCode")]
+    pub zip: String,
+}
+
+#[derive(FieldKinds)]
+pub struct Order {
+    pub total: u64,
+    pub tags: Vec<String>,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+pub struct Customer {
+    pub customer_id: u64,
+    pub home_address: Address,
+    pub billing_address: Option<Address>,
+    pub orders: Vec<Order>,
+    pub order_batches: Vec<Vec<Order>>,
+}
+
+fn paths<T: FieldKindsExt>() -> Vec<String> {
+    T::field_paths().into_iter().map(|p| p.path).collect()
+}
+
+#[test]
+fn leaf_paths() {
+    assert_eq!(
+        paths::<Customer>(),
+        vec![
+            "customer_id",
+            "home_address.city",
+            "home_address.zip",
+            "billing_address.city",
+            "billing_address.zip",
+            "orders[].total",
+            "orders[].tags",
+            "order_batches[][].total",
+            "order_batches[][].tags",
+        ]
+    );
+}
+
+#[test]
+fn serialized_paths() {
+    let serialized: Vec<_> = Customer::field_paths()
+        .into_iter()
+        .map(|p| p.serialized_path)
+        .collect();
+    assert_eq!(serialized[1], "homeAddress.city");
+    assert_eq!(serialized[2], "homeAddress.postCode");
+    assert_eq!(serialized[5], "orders[].total");
+}
+
+#[test]
+fn paths_carry_leaf_meta() {
+    let paths = Customer::field_paths();
+    let tags = paths.iter().find(|p| p.path == "orders[].tags").unwrap();
+    assert_eq!(tags.meta.name, "tags");
+    assert_eq!(tags.meta.category, Category::COLLECTION);
+}
+
+#[test]
+fn flat_structs_yield_field_names() {
+    assert_eq!(paths::<Order>(), Order::field_names());
+}
+
+#[test]
+fn find_by_path() {
+    let city = Customer::find_by_path("home_address.city").unwrap();
+    assert_eq!(city.name, "city");
+
+    let zip = Customer::find_by_path("homeAddress.postCode").unwrap();
+    assert_eq!(zip.name, "zip");
+
+    assert_eq!(Customer::find_by_path("orders[].total").unwrap().name, "total");
+    assert_eq!(Customer::find_by_path("orders.total").unwrap().name, "total");
+    assert_eq!(
+        Customer::find_by_path("home_address").unwrap().category,
+        Category::RECORD
+    );
+
+    assert!(Customer::find_by_path("home_address.street").is_none());
+    assert!(Customer::find_by_path("customer_id.city").is_none());
+    assert!(Customer::find_by_path("").is_none());
+}
+
+// `Self` would resolve to the generated marker types, not `TreeCategory`.
+#[allow(clippy::use_self)]
+#[derive(FieldKinds)]
+pub struct TreeCategory {
+    pub name: String,
+    pub parent: Option<Box<TreeCategory>>,
+    pub children: Vec<TreeCategory>,
+    pub featured: Vec<Order>,
+}
+
+#[test]
+fn recursive_fields_are_leaves() {
+    assert_eq!(
+        paths::<TreeCategory>(),
+        vec![
+            "name",
+            "parent",
+            "children",
+            "featured[].total",
+            "featured[].tags"
+        ]
+    );
+    let children = TreeCategory::find_by_path("children[].children[].name");
+    assert_eq!(children.unwrap().name, "name");
+}
+
+// Restricted visibilities are the point of this module.
+#[allow(clippy::redundant_pub_crate)]
+mod visibility {
+    use field_kinds::{FieldKinds, FieldKindsExt};
+
+    #[derive(FieldKinds)]
+    struct Row {
+        line: Line,
+    }
+
+    #[derive(FieldKinds)]
+    pub(crate) struct Line {
+        total: u64,
+    }
+
+    #[derive(FieldKinds)]
+    pub(super) struct Invoice {
+        lines: Vec<Line>,
+    }
+
+    #[derive(FieldKinds)]
+    pub(crate) struct Ledger {
+        lines: Vec<Line>,
+    }
+
+    pub mod nested {
+        use field_kinds::FieldKinds;
+
+        #[derive(FieldKinds)]
+        pub(in crate::visibility) struct Entry {
+            amount: i64,
+        }
+    }
+
+    #[test]
+    fn private_nested_records() {
+        assert_eq!(Row::field_paths()[0].path, "line.total");
+        assert_eq!(Invoice::field_paths()[0].path, "lines[].total");
+        assert_eq!(Ledger::field_paths()[0].path, "lines[].total");
+        assert_eq!(nested::Entry::field_names(), vec!["amount"]);
+    }
+}
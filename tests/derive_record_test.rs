@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Categorized, Category, FieldKinds, FieldKindsExt, NestedCategory, Numeric,
+    Schema, TypeCategory, VisitFields,
+};
+
+#[derive(FieldKinds)]
+pub struct Address {
+    pub street: String,
+    pub zip: u32,
+}
+
+#[derive(FieldKinds)]
+pub struct Customer {
+    pub name: String,
+    pub address: Address,
+    pub billing: Option<Address>,
+    pub previous: Vec<Address>,
+}
+
+#[test]
+fn derived_structs_are_records() {
+    assert_eq!(
+        <<Address as Categorized>::Category as TypeCategory>::CATEGORY,
+        Category::RECORD
+    );
+    assert_eq!(Customer::fields_by_category(Category::RECORD), vec!["address"]);
+}
+
+#[test]
+fn schema_points_to_nested_fields() {
+    let address = Customer::find_by_name("address").unwrap();
+    let fields = address.schema.unwrap().fields();
+    assert_eq!(fields, Address::FIELDS);
+    assert_eq!(address.schema, Some(Schema::of::<Address>()));
+    assert!(Customer::find_by_name("name").unwrap().schema.is_none());
+}
+
+#[test]
+fn record_fields_look_through_containers() {
+    for name in ["address", "billing", "previous"] {
+        let field = Customer::find_by_name(name).unwrap();
+        let nested: Vec<_> = field
+            .record_fields()
+            .unwrap()
+            .iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(nested, vec!["street", "zip"], "{name}");
+    }
+    let billing = Customer::find_by_name("billing").unwrap();
+    assert!(billing.schema.is_none());
+    assert_eq!(billing.inner_category(), Some(Category::RECORD));
+    assert!(
+        Customer::find_by_name("name")
+            .unwrap()
+            .record_fields()
+            .is_none()
+    );
+}
+
+// `Self` would resolve to the generated marker types, not `TreeNode`.
+#[allow(clippy::use_self)]
+#[derive(FieldKinds)]
+pub struct TreeNode {
+    pub label: String,
+    pub children: Vec<TreeNode>,
+    pub parent: Option<Box<TreeNode>>,
+}
+
+#[test]
+fn recursive_types() {
+    let children = TreeNode::find_by_name("children").unwrap();
+    let nested = children.record_fields().unwrap();
+    assert_eq!(nested, TreeNode::FIELDS);
+    let parent = TreeNode::find_by_name("parent").unwrap();
+    assert_eq!(parent.record_fields().unwrap()[0].name, "label");
+}
+
+#[derive(FieldKinds)]
+pub struct Page<T: Categorized> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+#[test]
+fn generic_records() {
+    let node = NestedCategory::of::<Page<Address>>();
+    assert_eq!(node.category, Category::RECORD);
+    let fields = node.schema.unwrap().fields();
+    assert_eq!(fields[0].record_fields(), Some(Address::FIELDS));
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(skip_categorized)]
+pub struct Money {
+    pub cents: i64,
+}
+
+impl Categorized for Money {
+    type Category = Numeric;
+}
+
+#[derive(FieldKinds)]
+pub struct Invoice {
+    pub amount: Money,
+    #[field_kinds(category = "record")]
+    pub meta: String,
+}
+
+#[test]
+fn skip_categorized_allows_manual_impl() {
+    assert_eq!(Invoice::field_category("amount"), Some(Category::NUMERIC));
+    assert_eq!(Money::field_names(), vec!["cents"]);
+}
+
+#[test]
+fn record_category_override() {
+    assert_eq!(Invoice::field_category("meta"), Some(Category::RECORD));
+}
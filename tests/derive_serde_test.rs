@@ -1,6 +1,6 @@
 #![allow(dead_code, non_snake_case)]
 
-use field_kinds::{FieldKinds, FieldKindsExt};
+use field_kinds::{FieldKinds, FieldKindsExt, VisitFields};
 
 #[derive(FieldKinds)]
 #[serde(rename_all = "camelCase")]
@@ -187,3 +187,212 @@ fn bare_serde_on_field_should_not_swallow_rename() {
          skipping the valid rename on the next attribute"
     );
 }
+
+#[derive(FieldKinds)]
+struct SerdeSkipStruct {
+    id: u64,
+    #[serde(skip)]
+    cache: Vec<u8>,
+    #[serde(skip_serializing)]
+    password: String,
+    #[serde(skip_deserializing)]
+    computed: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+#[test]
+fn serde_skip_flags_recorded() {
+    let flags: Vec<_> = SerdeSkipStruct::FIELDS
+        .iter()
+        .map(|f| (f.name, f.is_serialized(), f.is_deserialized()))
+        .collect();
+    assert_eq!(
+        flags,
+        vec![
+            ("id", true, true),
+            ("cache", false, false),
+            ("password", false, true),
+            ("computed", true, false),
+            ("note", true, true),
+        ]
+    );
+}
+
+#[test]
+fn serde_skipped_fields_kept_by_default() {
+    assert_eq!(SerdeSkipStruct::FIELD_COUNT, 5);
+    assert!(SerdeSkipStruct::has_field("cache"));
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(exclude_serde_skipped)]
+struct ExcludeSerdeSkipped {
+    id: u64,
+    #[serde(skip)]
+    cache: Vec<u8>,
+    #[serde(skip_serializing, skip_deserializing)]
+    scratch: Vec<u8>,
+    #[serde(skip_serializing)]
+    password: String,
+    #[serde(skip_deserializing)]
+    created_at: u64,
+}
+
+#[test]
+fn exclude_serde_skipped_drops_fully_skipped_fields() {
+    assert_eq!(
+        ExcludeSerdeSkipped::field_names(),
+        vec!["id", "password", "created_at"]
+    );
+}
+
+#[test]
+fn exclude_serde_skipped_filters_names_per_direction() {
+    assert_eq!(
+        ExcludeSerdeSkipped::serialized_names(),
+        vec!["id", "created_at"]
+    );
+    assert_eq!(
+        ExcludeSerdeSkipped::serialized_names_iter().collect::<Vec<_>>(),
+        vec!["id", "created_at"]
+    );
+    assert_eq!(
+        ExcludeSerdeSkipped::deserialize_names(),
+        vec!["id", "password"]
+    );
+    const { assert!(ExcludeSerdeSkipped::TYPE.exclude_serde_skipped) };
+}
+
+#[test]
+fn one_direction_skips_kept_without_opt_in() {
+    assert_eq!(
+        SerdeSkipStruct::serialized_names().len(),
+        SerdeSkipStruct::FIELD_COUNT
+    );
+    assert_eq!(
+        SerdeSkipStruct::deserialize_names().len(),
+        SerdeSkipStruct::FIELD_COUNT
+    );
+}
+
+#[derive(FieldKinds)]
+struct SerdeAttrsWithValues {
+    #[serde(default = "String::new", rename = "n")]
+    name: String,
+}
+
+#[test]
+fn rename_after_valued_serde_item() {
+    assert_eq!(SerdeAttrsWithValues::serialized_names(), vec!["n"]);
+}
+
+#[derive(FieldKinds)]
+struct DirectionalRename {
+    #[serde(rename(serialize = "userId", deserialize = "user_id"))]
+    id: u64,
+    #[serde(rename(deserialize = "fullName"))]
+    name: String,
+    #[serde(rename = "mail")]
+    email: String,
+}
+
+#[test]
+fn directional_field_rename() {
+    assert_eq!(
+        DirectionalRename::serialized_names(),
+        vec!["userId", "name", "mail"]
+    );
+    assert_eq!(
+        DirectionalRename::deserialize_names(),
+        vec!["user_id", "fullName", "mail"]
+    );
+}
+
+#[test]
+fn find_by_deserialize_name() {
+    assert_eq!(
+        DirectionalRename::find_by_deserialize_name("fullName")
+            .unwrap()
+            .name,
+        "name"
+    );
+    assert!(DirectionalRename::find_by_deserialize_name("userId").is_none());
+    assert!(DirectionalRename::find_by_serialized_name("userId").is_some());
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "kebab-case"))]
+struct DirectionalRenameAll {
+    user_name: String,
+    #[serde(rename(serialize = "ID"))]
+    user_id: u64,
+}
+
+#[test]
+fn directional_rename_all() {
+    assert_eq!(
+        DirectionalRenameAll::serialized_names(),
+        vec!["userName", "ID"]
+    );
+    assert_eq!(
+        DirectionalRenameAll::deserialize_names(),
+        vec!["user-name", "user-id"]
+    );
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all(serialize = "UPPERCASE"))]
+struct SerializeOnlyRenameAll {
+    user_name: String,
+}
+
+#[test]
+fn serialize_only_rename_all_keeps_deserialize_name() {
+    assert_eq!(SerializeOnlyRenameAll::serialized_names(), vec!["USER_NAME"]);
+    assert_eq!(SerializeOnlyRenameAll::deserialize_names(), vec!["user_name"]);
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+struct AliasStruct {
+    #[serde(alias = "username", alias = "login")]
+    user_name: String,
+    #[serde(rename = "mail", alias = "email_address")]
+    #[serde(alias = "e_mail")]
+    email: String,
+    active: bool,
+}
+
+#[test]
+fn aliases_recorded() {
+    let user_name = AliasStruct::find_by_name("user_name").unwrap();
+    assert_eq!(user_name.aliases, &["username", "login"]);
+    let email = AliasStruct::find_by_name("email").unwrap();
+    assert_eq!(email.aliases, &["email_address", "e_mail"]);
+    assert!(
+        AliasStruct::find_by_name("active")
+            .unwrap()
+            .aliases
+            .is_empty()
+    );
+}
+
+#[test]
+fn find_by_any_name() {
+    for name in ["user_name", "userName", "username", "login"] {
+        assert_eq!(
+            AliasStruct::find_by_any_name(name).unwrap().name,
+            "user_name"
+        );
+    }
+    for name in ["email", "mail", "email_address", "e_mail"] {
+        assert_eq!(AliasStruct::find_by_any_name(name).unwrap().name, "email");
+    }
+    assert!(AliasStruct::find_by_any_name("unknown").is_none());
+}
+
+#[test]
+fn find_by_serialized_name_ignores_aliases() {
+    assert!(AliasStruct::find_by_serialized_name("login").is_none());
+}
@@ -0,0 +1,182 @@
+#![allow(dead_code)]
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use field_kinds::{Categorized, FieldKinds, FieldSetError, SetFieldFromStr};
+
+#[derive(FieldKinds)]
+pub struct Retry {
+    pub attempts: u8,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct Config {
+    pub pool_size: u32,
+    pub host: String,
+    #[field_kinds(category = "text")]
+    pub listen: Ipv4Addr,
+    pub verbose: bool,
+    pub timeout: Option<u64>,
+    pub peers: Vec<String>,
+    #[field_kinds(skip)]
+    pub secret: String,
+    #[serde(flatten)]
+    pub retry: Retry,
+}
+
+fn config() -> Config {
+    Config {
+        pool_size: 5,
+        host: "localhost".into(),
+        listen: Ipv4Addr::LOCALHOST,
+        verbose: false,
+        timeout: None,
+        peers: Vec::new(),
+        secret: String::new(),
+        retry: Retry { attempts: 1 },
+    }
+}
+
+#[test]
+fn sets_by_rust_or_serialized_name() {
+    let mut config = config();
+    config.set_field_from_str("pool_size", "20").unwrap();
+    assert_eq!(config.pool_size, 20);
+    config.set_field_from_str("POOL_SIZE", "30").unwrap();
+    assert_eq!(config.pool_size, 30);
+    config.set_field_from_str("HOST", "db.internal").unwrap();
+    assert_eq!(config.host, "db.internal");
+    config.set_field_from_str("listen", "10.0.0.1").unwrap();
+    assert_eq!(config.listen, Ipv4Addr::new(10, 0, 0, 1));
+    config.set_field_from_str("VERBOSE", "true").unwrap();
+    assert!(config.verbose);
+}
+
+#[test]
+fn flattened_fields() {
+    let mut config = config();
+    config.set_field_from_str("attempts", "3").unwrap();
+    assert_eq!(config.retry.attempts, 3);
+    assert!(matches!(
+        config.set_field_from_str("retry", "3"),
+        Err(FieldSetError::UnknownField { .. })
+    ));
+}
+
+#[test]
+fn unknown_field() {
+    let mut config = config();
+    let err = config.set_field_from_str("pool", "1").unwrap_err();
+    assert_eq!(
+        err,
+        FieldSetError::UnknownField {
+            name: "pool".into()
+        }
+    );
+    assert_eq!(err.to_string(), "unknown field `pool`");
+    assert!(matches!(
+        config.set_field_from_str("secret", "x"),
+        Err(FieldSetError::UnknownField { .. })
+    ));
+}
+
+#[test]
+fn parse_failure() {
+    let mut config = config();
+    let err = config.set_field_from_str("pool_size", "-1").unwrap_err();
+    let FieldSetError::Parse {
+        field,
+        value,
+        message,
+    } = &err
+    else {
+        panic!("expected parse error, got {err:?}");
+    };
+    assert_eq!(*field, "pool_size");
+    assert_eq!(value, "-1");
+    assert_eq!(*message, u32::from_str("-1").unwrap_err().to_string());
+    assert_eq!(config.pool_size, 5);
+    assert!(
+        err.to_string()
+            .starts_with("invalid value \"-1\" for field `pool_size`")
+    );
+}
+
+#[test]
+fn unsupported_type() {
+    let mut config = config();
+    assert_eq!(
+        config.set_field_from_str("PEERS", "a,b"),
+        Err(FieldSetError::Unsupported { field: "peers" })
+    );
+}
+
+#[test]
+fn optional_fields() {
+    let mut config = config();
+    config.set_field_from_str("TIMEOUT", "10").unwrap();
+    assert_eq!(config.timeout, Some(10));
+    assert!(matches!(
+        config.set_field_from_str("timeout", "soon"),
+        Err(FieldSetError::Parse {
+            field: "timeout",
+            ..
+        })
+    ));
+    config.set_field_from_str("timeout", "").unwrap();
+    assert_eq!(config.timeout, None);
+}
+
+#[derive(FieldKinds)]
+pub struct Bounded<T: Categorized + FromStr>
+where
+    T::Err: std::fmt::Display,
+{
+    pub value: T,
+}
+
+#[derive(FieldKinds)]
+pub struct Unbounded<T: Categorized> {
+    pub value: T,
+    pub count: usize,
+}
+
+#[test]
+fn generic_fields() {
+    let mut bounded = Bounded { value: 1_i64 };
+    bounded.set_field_from_str("value", "-7").unwrap();
+    assert_eq!(bounded.value, -7);
+
+    let mut unbounded = Unbounded {
+        value: 1_i64,
+        count: 0,
+    };
+    assert_eq!(
+        unbounded.set_field_from_str("value", "2"),
+        Err(FieldSetError::Unsupported { field: "value" })
+    );
+    unbounded.set_field_from_str("count", "2").unwrap();
+    assert_eq!(unbounded.count, 2);
+}
+
+#[derive(FieldKinds)]
+pub struct Borrowed<'a> {
+    pub label: &'a str,
+    pub size: u16,
+}
+
+#[test]
+fn structs_with_lifetimes() {
+    let mut borrowed = Borrowed {
+        label: "x",
+        size: 0,
+    };
+    borrowed.set_field_from_str("size", "9").unwrap();
+    assert_eq!(borrowed.size, 9);
+    assert_eq!(
+        borrowed.set_field_from_str("label", "y"),
+        Err(FieldSetError::Unsupported { field: "label" })
+    );
+}
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
-use field_kinds::{FieldKinds, FieldKindsExt, VisitFields};
+use field_kinds::{
+    FieldInfo, FieldKinds, FieldKindsExt, TagValue, VisitFields,
+};
 
 #[derive(FieldKinds)]
 struct TaggedStruct {
@@ -79,3 +81,100 @@ fn multiple_field_tags_attrs_should_merge() {
         field.tags
     );
 }
+
+#[derive(FieldKinds)]
+#[field_tags("audit", retention_days = 90)]
+struct Settings {
+    #[field_tags("indexed", max_len = 64, unit = "chars")]
+    name: String,
+    #[field_tags(unit = "ms", min = -1, scale = 0.5, required = true)]
+    timeout: i64,
+    #[field_tags(unit = "ms", r#type = "duration")]
+    delay: u64,
+    #[field_tags(max_len = 64)]
+    #[field_tags(max_len = 128)]
+    code: String,
+}
+
+#[test]
+fn key_value_tags_keep_bare_tags() {
+    assert_eq!(Settings::fields_by_tag("indexed"), vec!["name"]);
+    let name = Settings::find_by_name("name").unwrap();
+    assert_eq!(name.tags, &["indexed"]);
+    assert_eq!(
+        name.tag_values,
+        &[
+            ("max_len", TagValue::Int(64)),
+            ("unit", TagValue::Str("chars"))
+        ]
+    );
+}
+
+#[test]
+fn tag_value_kinds() {
+    let timeout = Settings::find_by_name("timeout").unwrap();
+    assert_eq!(timeout.tag_value("unit"), Some(TagValue::Str("ms")));
+    assert_eq!(timeout.tag_value("min"), Some(TagValue::Int(-1)));
+    assert_eq!(timeout.tag_value("scale"), Some(TagValue::Float(0.5)));
+    assert_eq!(timeout.tag_value("required"), Some(TagValue::Bool(true)));
+    assert_eq!(timeout.tag_value("missing"), None);
+}
+
+#[test]
+fn raw_identifier_keys() {
+    let delay = Settings::find_by_name("delay").unwrap();
+    assert_eq!(delay.tag_value("type"), Some(TagValue::Str("duration")));
+}
+
+#[test]
+fn first_repeated_key_wins() {
+    let code = Settings::find_by_name("code").unwrap();
+    assert_eq!(code.tag_value("max_len"), Some(TagValue::Int(64)));
+    assert_eq!(code.tag_values.len(), 2);
+}
+
+#[test]
+fn fields_with_tag_value() {
+    assert_eq!(
+        Settings::fields_with_tag_value("unit", "ms"),
+        vec!["timeout", "delay"]
+    );
+    assert_eq!(
+        Settings::fields_with_tag_value("max_len", 64),
+        vec!["name", "code"]
+    );
+    assert_eq!(Settings::fields_with_tag_value("scale", 0.5), vec!["timeout"]);
+    assert_eq!(
+        Settings::fields_with_tag_value("required", true),
+        vec!["timeout"]
+    );
+    assert!(Settings::fields_with_tag_value("unit", 64).is_empty());
+}
+
+#[test]
+fn tag_value_comparisons() {
+    assert_eq!(TagValue::Str("ms"), "ms");
+    assert_eq!(TagValue::Int(3), 3);
+    assert_ne!(TagValue::Int(3), TagValue::Float(3.0));
+    assert_eq!(TagValue::Int(3).as_float(), Some(3.0));
+    assert_eq!(TagValue::Str("ms").as_int(), None);
+    assert_eq!(TagValue::Float(0.5).to_string(), "0.5");
+    assert_eq!(TagValue::Str("ms").to_string(), "ms");
+}
+
+#[test]
+fn container_tag_values() {
+    assert_eq!(Settings::TYPE.tags, &["audit"]);
+    assert_eq!(
+        Settings::TYPE.tag_value("retention_days"),
+        Some(TagValue::Int(90))
+    );
+}
+
+#[test]
+fn field_info_tag_values() {
+    assert_eq!(
+        <settings_fields::Timeout as FieldInfo>::TAG_VALUES[0],
+        ("unit", TagValue::Str("ms"))
+    );
+}
@@ -0,0 +1,66 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Categorized, Category, FieldKinds, FieldKindsExt, NestedCategory,
+    NumericClass, VisitFields,
+};
+use std::sync::Arc;
+
+#[derive(FieldKinds)]
+#[field_kinds(transparent)]
+pub struct UserId(pub u64);
+
+#[derive(FieldKinds)]
+#[field_kinds(transparent)]
+pub struct Email {
+    pub value: String,
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(transparent)]
+pub struct Tags(pub Vec<String>);
+
+#[derive(FieldKinds)]
+#[field_kinds(transparent)]
+pub struct Wrapper<T: Categorized> {
+    pub inner: T,
+    #[field_kinds(skip)]
+    pub cache: Option<String>,
+}
+
+#[derive(FieldKinds)]
+struct Account {
+    id: UserId,
+    email: Option<Email>,
+    tags: Tags,
+    owner: Arc<UserId>,
+    score: Wrapper<f32>,
+}
+
+#[test]
+fn transparent_newtypes_take_inner_category() {
+    assert_eq!(Account::field_category("id"), Some(Category::NUMERIC));
+    assert_eq!(Account::field_category("tags"), Some(Category::COLLECTION));
+    assert_eq!(Account::field_category("owner"), Some(Category::NUMERIC));
+    assert_eq!(Account::field_category("score"), Some(Category::NUMERIC));
+}
+
+#[test]
+fn transparent_newtypes_forward_inner_categories() {
+    let email = Account::find_by_name("email").unwrap();
+    assert_eq!(email.inner_category(), Some(Category::TEXT));
+
+    let tags: Vec<_> = NestedCategory::of::<Tags>().chain().collect();
+    assert_eq!(tags, vec![Category::COLLECTION, Category::TEXT]);
+}
+
+#[test]
+fn transparent_still_derives_fields() {
+    assert_eq!(Email::field_names(), vec!["value"]);
+    assert_eq!(Wrapper::<u8>::FIELD_COUNT, 1);
+    assert!(<UserId as Categorized>::KEY.is_none());
+    assert_eq!(
+        <UserId as Categorized>::NUMERIC_CLASS,
+        Some(NumericClass::Integer)
+    );
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Categorized, Category, FieldKinds, FieldKindsExt, VisitFields,
+};
+
+#[derive(FieldKinds)]
+struct Point(f64, f64);
+
+#[test]
+fn tuple_struct_positional_names() {
+    assert_eq!(Point::FIELD_COUNT, 2);
+    assert_eq!(Point::field_names(), vec!["0", "1"]);
+    assert_eq!(Point::serialized_names(), vec!["0", "1"]);
+}
+
+#[test]
+fn tuple_struct_indices() {
+    let indices: Vec<_> = Point::FIELDS.iter().map(|f| f.index).collect();
+    assert_eq!(indices, vec![0, 1]);
+    assert_eq!(Point::find_by_index(1).unwrap().name, "1");
+    assert!(Point::find_by_index(2).is_none());
+}
+
+#[derive(FieldKinds)]
+struct UserId(u64);
+
+#[test]
+fn newtype_struct() {
+    assert_eq!(UserId::field_names(), vec!["0"]);
+    assert_eq!(UserId::field_category("0"), Some(Category::NUMERIC));
+}
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "UPPERCASE")]
+struct Labeled(
+    #[field_tags("label")] String,
+    #[field_kinds(skip)] Vec<u8>,
+    bool,
+);
+
+#[test]
+fn tuple_struct_attributes() {
+    assert_eq!(Labeled::field_names(), vec!["0", "2"]);
+    assert_eq!(Labeled::serialized_names(), vec!["0", "2"]);
+    assert_eq!(Labeled::fields_by_tag("label"), vec!["0"]);
+    assert_eq!(Labeled::find_by_index(2).unwrap().category, Category::BOOL);
+    assert!(Labeled::find_by_index(1).is_none());
+}
+
+#[derive(FieldKinds)]
+struct Marker;
+
+#[test]
+fn unit_struct_has_no_fields() {
+    assert_eq!(Marker::FIELD_COUNT, 0);
+    assert!(Marker::FIELDS.is_empty());
+}
+
+#[derive(FieldKinds)]
+struct Wrapped<T: Categorized>(T, &'static str);
+
+#[test]
+fn generic_tuple_struct() {
+    assert_eq!(Wrapped::<bool>::field_category("0"), Some(Category::BOOL));
+    assert_eq!(Wrapped::<bool>::field_category("1"), Some(Category::TEXT));
+}
+
+#[derive(FieldKinds)]
+struct Named {
+    first: u8,
+    #[field_kinds(skip)]
+    second: u8,
+    third: u8,
+}
+
+#[test]
+fn named_struct_indices_count_skipped_fields() {
+    let indices: Vec<_> = Named::FIELDS.iter().map(|f| f.index).collect();
+    assert_eq!(indices, vec![0, 2]);
+}
+
+const fn field_count<T: VisitFields>() -> usize {
+    T::FIELD_COUNT
+}
+
+#[test]
+fn shapes_are_interchangeable_in_generic_code() {
+    assert_eq!(field_count::<Point>(), 2);
+    assert_eq!(field_count::<UserId>(), 1);
+    assert_eq!(field_count::<Marker>(), 0);
+}
@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+
+use field_kinds::{Categorized, FieldKinds, VisitFields};
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    pub user_id: u64,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename = "audit_entry")]
+#[field_tags("audit", "internal")]
+pub struct AuditEntry {
+    #[field_tags("pii")]
+    pub actor: String,
+}
+
+#[derive(FieldKinds)]
+#[serde(rename(serialize = "out", deserialize = "in"))]
+pub struct Directional {
+    pub value: u8,
+}
+
+#[derive(FieldKinds)]
+pub struct Page<T: Categorized> {
+    pub items: Vec<T>,
+}
+
+mod nested {
+    use field_kinds::FieldKinds;
+
+    #[derive(FieldKinds)]
+    pub struct Inner;
+}
+
+#[test]
+fn defaults_to_type_name() {
+    let meta = UserProfile::TYPE;
+    assert_eq!(meta.name, "UserProfile");
+    assert_eq!(meta.serialized_name, "UserProfile");
+    assert_eq!(meta.deserialize_name, "UserProfile");
+    assert!(meta.tags.is_empty());
+}
+
+#[test]
+fn container_rename() {
+    assert_eq!(AuditEntry::TYPE.serialized_name, "audit_entry");
+    assert_eq!(Directional::TYPE.serialized_name, "out");
+    assert_eq!(Directional::TYPE.deserialize_name, "in");
+}
+
+#[test]
+fn container_tags() {
+    assert_eq!(AuditEntry::TYPE.tags, &["audit", "internal"]);
+    assert!(AuditEntry::TYPE.has_tag("audit"));
+    assert!(!AuditEntry::TYPE.has_tag("pii"));
+    assert_eq!(AuditEntry::FIELDS[0].tags, &["pii"]);
+}
+
+#[test]
+fn module_path() {
+    assert_eq!(UserProfile::TYPE.module_path, "derive_type_meta_test");
+    assert_eq!(
+        nested::Inner::TYPE.module_path,
+        "derive_type_meta_test::nested"
+    );
+}
+
+#[test]
+fn generic_types() {
+    assert_eq!(Page::<u8>::TYPE.name, "Page");
+}
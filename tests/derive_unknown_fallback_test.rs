@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use field_kinds::{
+    Category, FieldInfo, FieldKinds, FieldKindsExt, TypeCategory, VisitFields,
+    VisitVariants,
+};
+use std::net::Ipv4Addr;
+
+/// A legacy type without a `Categorized` impl.
+pub struct LegacyHandle(u32);
+
+#[derive(FieldKinds)]
+#[field_kinds(unknown_fallback)]
+pub struct Legacy {
+    pub id: u64,
+    pub handle: LegacyHandle,
+    pub address: Ipv4Addr,
+    pub names: Vec<String>,
+    pub handles: Vec<LegacyHandle>,
+    pub nickname: Option<String>,
+    #[field_kinds(category = "numeric")]
+    pub counter: LegacyHandle,
+}
+
+#[test]
+fn categorized_types_keep_their_category() {
+    assert_eq!(Legacy::field_category("id"), Some(Category::NUMERIC));
+    assert_eq!(Legacy::field_category("names"), Some(Category::COLLECTION));
+    assert_eq!(Legacy::field_category("handles"), Some(Category::COLLECTION));
+    assert_eq!(Legacy::field_category("counter"), Some(Category::NUMERIC));
+}
+
+#[test]
+fn uncategorized_types_fall_back_to_unknown() {
+    assert_eq!(
+        Legacy::fields_by_category(Category::UNKNOWN),
+        vec!["handle", "address"]
+    );
+}
+
+#[test]
+fn nested_categories_are_probed() {
+    let nickname = Legacy::find_by_name("nickname").unwrap();
+    assert_eq!(nickname.inner_category(), Some(Category::TEXT));
+    let handle = Legacy::find_by_name("handle").unwrap();
+    assert_eq!(handle.inner_category(), None);
+    let handles = Legacy::find_by_name("handles").unwrap();
+    assert_eq!(handles.inner_category(), Some(Category::UNKNOWN));
+}
+
+#[test]
+fn field_info_uses_probed_category() {
+    type Handle = legacy_fields::Handle;
+    type Id = legacy_fields::Id;
+    assert_eq!(<Handle as FieldInfo>::CATEGORY_NAME, "unknown");
+    assert_eq!(
+        <<Handle as FieldInfo>::Category as TypeCategory>::CATEGORY,
+        Category::UNKNOWN
+    );
+    assert_eq!(<Id as FieldInfo>::CATEGORY_NAME, "numeric");
+    assert_eq!(Legacy::FIELD_COUNT, 7);
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(unknown_fallback)]
+pub enum Job {
+    Running { host: Ipv4Addr, pid: u32 },
+}
+
+#[test]
+fn fallback_on_enum_fields() {
+    let fields = Job::VARIANTS[0].fields;
+    assert_eq!(fields[0].category, Category::UNKNOWN);
+    assert_eq!(fields[1].category, Category::NUMERIC);
+}
+
+#[derive(FieldKinds)]
+#[field_kinds(unknown_fallback)]
+pub struct Collide {
+    pub foo: u32,
+    pub foo_category: String,
+}
+
+#[test]
+fn fallback_markers_do_not_collide_with_field_markers() {
+    assert_eq!(Collide::field_category("foo"), Some(Category::NUMERIC));
+    assert_eq!(Collide::field_category("foo_category"), Some(Category::TEXT));
+    assert_eq!(
+        <collide_fields::FooCategory as FieldInfo>::NAME,
+        "foo_category"
+    );
+}
@@ -0,0 +1,149 @@
+#![allow(dead_code)]
+
+use std::any::Any;
+
+use field_kinds::{FieldKinds, FieldMeta, FieldValueVisitor, VisitValues};
+
+#[derive(FieldKinds)]
+pub struct Audit {
+    pub created_by: String,
+    pub version: u32,
+}
+
+#[derive(FieldKinds)]
+pub struct Document {
+    pub id: u64,
+    pub title: String,
+    pub published: bool,
+    pub summary: Option<String>,
+    pub tags: Vec<String>,
+    pub raw: Box<[u8]>,
+    #[field_kinds(skip)]
+    pub cache: Vec<u8>,
+    #[serde(flatten)]
+    pub audit: Audit,
+}
+
+fn document() -> Document {
+    Document {
+        id: 42,
+        title: "Draft".into(),
+        published: false,
+        summary: None,
+        tags: vec!["a".into(), "b".into()],
+        raw: Box::new([1, 2, 3]),
+        cache: Vec::new(),
+        audit: Audit {
+            created_by: "ann".into(),
+            version: 3,
+        },
+    }
+}
+
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl FieldValueVisitor for Recorder {
+    fn visit_numeric(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let value = value
+            .downcast_ref::<u64>()
+            .map(ToString::to_string)
+            .or_else(|| value.downcast_ref::<u32>().map(ToString::to_string));
+        self.0
+            .push(format!("numeric {} = {}", meta.name, value.unwrap()));
+    }
+
+    fn visit_text(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let text = value.downcast_ref::<String>().unwrap();
+        self.0.push(format!("text {} = {text}", meta.name));
+    }
+
+    fn visit_bool(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let flag = value.downcast_ref::<bool>().unwrap();
+        self.0.push(format!("bool {} = {flag}", meta.name));
+    }
+
+    fn visit_optional(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let is_some = value.downcast_ref::<Option<String>>().unwrap().is_some();
+        self.0.push(format!("optional {} = {is_some}", meta.name));
+    }
+
+    fn visit_collection(&mut self, meta: &'static FieldMeta, value: &dyn Any) {
+        let len = value.downcast_ref::<Vec<String>>().unwrap().len();
+        self.0.push(format!("collection {} = {len}", meta.name));
+    }
+
+    fn visit_other(&mut self, meta: &'static FieldMeta, _value: &dyn Any) {
+        self.0
+            .push(format!("other {} ({})", meta.name, meta.category));
+    }
+}
+
+#[test]
+fn dispatches_by_category_in_field_order() {
+    let mut recorder = Recorder::default();
+    document().visit_values(&mut recorder);
+    assert_eq!(
+        recorder.0,
+        vec![
+            "numeric id = 42",
+            "text title = Draft",
+            "bool published = false",
+            "optional summary = false",
+            "collection tags = 2",
+            "other raw (binary)",
+            "text created_by = ann",
+            "numeric version = 3",
+        ]
+    );
+}
+
+#[test]
+fn flattened_values_keep_outer_meta() {
+    struct Flattened(Vec<(&'static str, Option<&'static str>)>);
+
+    impl FieldValueVisitor for Flattened {
+        fn visit_other(&mut self, meta: &'static FieldMeta, _value: &dyn Any) {
+            self.0.push((meta.name, meta.flattened_from));
+        }
+    }
+
+    let mut visitor = Flattened(Vec::new());
+    document().visit_values(&mut visitor);
+    assert_eq!(visitor.0[6], ("created_by", Some("audit")));
+    assert_eq!(visitor.0.len(), 8);
+}
+
+#[test]
+fn visit_field_sees_every_field() {
+    struct Names(Vec<&'static str>);
+
+    impl FieldValueVisitor for Names {
+        fn visit_field(&mut self, meta: &'static FieldMeta, _value: &dyn Any) {
+            self.0.push(meta.name);
+        }
+    }
+
+    let mut names = Names(Vec::new());
+    document().visit_values(&mut names);
+    assert!(!names.0.contains(&"cache"));
+    assert_eq!(names.0.len(), 8);
+}
+
+#[test]
+fn default_visitor_does_nothing() {
+    struct Noop;
+    impl FieldValueVisitor for Noop {}
+
+    document().visit_values(&mut Noop);
+}
+
+#[derive(FieldKinds)]
+pub struct Empty;
+
+#[test]
+fn unit_structs() {
+    let mut recorder = Recorder::default();
+    Empty.visit_values(&mut recorder);
+    assert!(recorder.0.is_empty());
+}
@@ -0,0 +1,67 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+#![allow(dead_code)]
+
+use field_kinds::{Categorized, Category, TypeCategory};
+
+const fn category_of<T: Categorized>() -> Category {
+    <T::Category as TypeCategory>::CATEGORY
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_types {
+    use super::category_of;
+    use chrono::{
+        DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta,
+        Utc,
+    };
+    use field_kinds::{Category, FieldKinds, FieldKindsExt};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::datetime_utc(category_of::<DateTime<Utc>>())]
+    #[case::datetime_fixed(category_of::<DateTime<FixedOffset>>())]
+    #[case::naive_datetime(category_of::<NaiveDateTime>())]
+    #[case::naive_date(category_of::<NaiveDate>())]
+    #[case::naive_time(category_of::<NaiveTime>())]
+    #[case::time_delta(category_of::<TimeDelta>())]
+    fn chrono_types_are_temporal(#[case] category: Category) {
+        assert_eq!(category, Category::TEMPORAL);
+    }
+
+    #[derive(FieldKinds)]
+    struct Event {
+        name: String,
+        at: DateTime<Utc>,
+        ends: Option<NaiveDate>,
+    }
+
+    #[test]
+    fn derive_with_chrono_fields() {
+        assert_eq!(Event::fields_by_category(Category::TEMPORAL), vec!["at"]);
+        assert_eq!(
+            Event::fields_by_inner_category(Category::TEMPORAL),
+            vec!["ends"]
+        );
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_types {
+    use super::category_of;
+    use field_kinds::Category;
+    use rstest::rstest;
+    use time::{
+        Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime,
+    };
+
+    #[rstest]
+    #[case::offset_datetime(category_of::<OffsetDateTime>())]
+    #[case::primitive_datetime(category_of::<PrimitiveDateTime>())]
+    #[case::utc_datetime(category_of::<UtcDateTime>())]
+    #[case::date(category_of::<Date>())]
+    #[case::time(category_of::<Time>())]
+    #[case::duration(category_of::<Duration>())]
+    fn time_types_are_temporal(#[case] category: Category) {
+        assert_eq!(category, Category::TEMPORAL);
+    }
+}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+#[field_tags(bogus_ident)]
+enum Event {
+    Created { id: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+struct User {
+    #[field_kinds(skip = true)]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+struct User {
+    #[field_tags("pii", 42)]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+struct User {
+    #[field_tags(max = 18446744073709551615)]
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+#[field_kinds(transparant)]
+struct UserId(u64);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+struct User {
+    #[field_kinds(skp)]
+    id: u64,
+}
+
+fn main() {}
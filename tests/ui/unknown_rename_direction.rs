@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+struct User {
+    #[serde(rename(serialise = "id"))]
+    user_id: u64,
+}
+
+fn main() {}
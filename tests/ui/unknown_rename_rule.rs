@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+#[serde(rename_all = "camel")]
+struct User {
+    user_id: u64,
+}
+
+fn main() {}
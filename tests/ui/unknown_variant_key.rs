@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+enum Event {
+    #[field_kinds(hidden)]
+    Created,
+}
+
+fn main() {}
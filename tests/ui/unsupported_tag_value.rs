@@ -0,0 +1,11 @@
+use field_kinds::FieldKinds;
+
+const MAX: usize = 64;
+
+#[derive(FieldKinds)]
+struct User {
+    #[field_tags(max_len = MAX)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use field_kinds::FieldKinds;
+
+#[derive(FieldKinds)]
+enum Event {
+    #[field_tags(also_bogus)]
+    Created { id: u64 },
+}
+
+fn main() {}
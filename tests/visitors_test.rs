@@ -2,7 +2,7 @@
 
 use field_kinds::{
     Category, FieldInfo, FieldKindsExt, FieldMeta, Numeric, Text, TypeCategory,
-    VisitFields,
+    TypeMeta, VisitFields,
 };
 use rstest::rstest;
 
@@ -45,6 +45,8 @@ impl VisitFields for TestStruct {
         ),
         FieldMeta::new("field_b", "field_b", Category::TEXT, &["indexed"]),
     ];
+    const TYPE: TypeMeta =
+        TypeMeta::new("TestStruct", "TestStruct", module_path!(), &[]);
 }
 
 #[test]
@@ -320,3 +322,14 @@ fn serialized_names_iter() {
     let names: Vec<_> = TestStruct::serialized_names_iter().collect();
     assert_eq!(names, vec!["fieldA", "field_b"]);
 }
+
+#[rstest]
+#[case::rust_name("user_name", true)]
+#[case::serialized_name("userName", true)]
+#[case::alias("login", true)]
+#[case::other("email", false)]
+fn has_name(#[case] name: &str, #[case] expected: bool) {
+    let meta = FieldMeta::new("user_name", "userName", Category::TEXT, &[])
+        .with_aliases(&["login"]);
+    assert_eq!(meta.has_name(name), expected);
+}